@@ -1,8 +1,16 @@
-pub mod day1;
-pub mod day2;
-pub mod day3;
-pub mod day4;
-pub mod day5;
-pub mod day6;
-pub mod day7;
-pub mod day8;
\ No newline at end of file
+use std::sync::OnceLock;
+
+use crate::runner::registry::Registry;
+
+pub mod year2024;
+
+pub fn create_registry() -> Registry {
+    let mut registry = Registry::new();
+    year2024::register(&mut registry);
+    registry
+}
+
+pub fn registry() -> &'static Registry {
+    static REGISTRY: OnceLock<Registry> = OnceLock::new();
+    REGISTRY.get_or_init(create_registry)
+}
@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+
+use crate::runner::answer::Answer;
+use crate::runner::context::Context;
+use crate::runner::solution::{Metadata, Solution};
+use crate::utility::generic_error::{GenericError, GenericResult};
+use crate::utility::input_lines::{parse_lines, Line};
+
+type Lists = (Vec<i32>, Vec<i32>);
+
+fn load_pair(line : &Line) -> GenericResult<(i32, i32)> {
+    let mut words = line.text.split_whitespace();
+    let (Some(first), Some(second), None) = (words.next(), words.next(), words.next()) else {
+        return Err(line.error(GenericError::BasicError("Expected exactly two numbers".to_string())));
+    };
+    Ok((line.parse(first)?, line.parse(second)?))
+}
+
+fn populate_lists(input : &str) -> GenericResult<Lists> {
+    let mut list1 : Vec<i32> = Vec::new();
+    let mut list2 : Vec<i32> = Vec::new();
+
+    let pairs = parse_lines(input, |line| load_pair(line).map_err(|e| e.with_hint("expected two numbers per line, one for each list")))?;
+    for (first, second) in pairs {
+        crate::log_trace!("Values: {} {}", first, second);
+        list1.push(first);
+        list2.push(second);
+    }
+
+    Ok((list1, list2))
+}
+
+fn part_1(lists : &Lists) -> GenericResult<usize> {
+    let mut list1 = lists.0.clone();
+    let mut list2 = lists.1.clone();
+
+    list1.sort();
+    list2.sort();
+
+    let mut result = 0;
+
+    for value_pair in list1.iter().zip(list2.iter()) {
+        result += (value_pair.0 - value_pair.1).unsigned_abs() as usize;
+    }
+
+    Ok(result)
+}
+
+fn part_2(lists : &Lists) -> GenericResult<usize> {
+    let mut occurrences_in_list2 : HashMap<i32, usize> = HashMap::new();
+
+    for value in lists.1.iter() {
+        (*occurrences_in_list2.entry(*value).or_insert(0)) += 1
+    }
+
+    let mut result = 0;
+
+    for value in lists.0.iter() {
+        result += (*value as usize) * occurrences_in_list2.get(value).unwrap_or(&0);
+    }
+
+    Ok(result)
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Lists;
+
+    fn metadata(&self) -> Metadata {
+        Metadata { year: 2024, day: 1, title: "Historian Hysteria" }
+    }
+
+    fn parse(&self, input: &str) -> GenericResult<Self::Input> {
+        populate_lists(input)
+    }
+
+    fn part_1(&self, input: &Self::Input, _context: &Context) -> GenericResult<Answer> {
+        part_1(input).map(Answer::from)
+    }
+
+    fn part_2(&self, input: &Self::Input, _context: &Context) -> GenericResult<Answer> {
+        part_2(input).map(Answer::from)
+    }
+}
+
+#[test]
+pub fn run_test_1() -> GenericResult<()> {
+    let lists = Day1.parse(&std::fs::read_to_string("data/2024/day1/example.txt")?)?;
+    assert_eq!(Day1.part_1(&lists, &Context::default())?, Answer::Unsigned(11));
+    Ok(())
+}
+
+#[test]
+pub fn run_test_2() -> GenericResult<()> {
+    let lists = Day1.parse(&std::fs::read_to_string("data/2024/day1/example.txt")?)?;
+    assert_eq!(Day1.part_2(&lists, &Context::default())?, Answer::Unsigned(31));
+    Ok(())
+}
+
+#[test]
+pub fn run_test_malformed_input() {
+    assert!(matches!(Day1.parse("3   4\n4   x\n"), Err(GenericError::Located { line: 2, column: 5, .. })));
+    assert!(matches!(Day1.parse("3   4\n4\n"), Err(GenericError::Located { line: 2, column: 1, .. })));
+    assert!(matches!(Day1.parse("3   4   5\n"), Err(GenericError::Located { line: 1, column: 1, .. })));
+}
@@ -0,0 +1,170 @@
+use crate::runner::answer::Answer;
+use crate::runner::context::Context;
+use crate::runner::solution::{Metadata, Part, Solution};
+use crate::utility::generic_error::{GenericError, GenericResult};
+use crate::utility::input_lines::{parse_lines, Line};
+
+#[derive(PartialEq, Eq)]
+enum Direction {
+    Unknown,
+    Increasing,
+    Decreasing,
+}
+
+fn create_dampened_vec(original_vec : &[i32], remove_index : usize) -> Vec<i32> {
+    let mut result = Vec::new();
+    result.extend(&original_vec[..remove_index]);
+    result.extend(&original_vec[(remove_index+1)..]);
+    result
+}
+
+fn is_stable(values : &[i32], allow_dampening : bool) -> bool {
+    let mut previous_value = values[0];
+    let mut direction = Direction::Unknown;
+
+    let mut result = true;
+
+    let attempt_dampening = |allow_dampening : bool, values : &[i32], error_found_at : usize| -> bool {
+        if !allow_dampening {
+            return false;
+        }
+
+        let mut result = is_stable(&create_dampened_vec(values, error_found_at), false);
+
+        // In some very early cases, removing the first or second index would create a stable report rather than the current index
+        if error_found_at <= 2 {
+            if is_stable(&create_dampened_vec(values, 0), false) {
+                result = true;
+            }
+
+            if error_found_at == 2 && is_stable(&create_dampened_vec(values, 1), false) {
+                result = true;
+            }
+        }
+
+        result
+    };
+
+    for current_index in 1..values.len() {
+        let current_value = values[current_index];
+        let difference = current_value - previous_value;
+
+        if difference == 0 || difference.abs() > 3 {
+            crate::log_trace!("Unstable: difference was {}", difference);
+            result = attempt_dampening(allow_dampening, values, current_index);
+            break;
+        }
+
+        if difference > 0 {
+            // Unknown is valid, so just check for equality with opposite direction
+            if direction == Direction::Decreasing {
+                crate::log_trace!("Unstable: Increasing changed to decreasing");
+                result = attempt_dampening(allow_dampening, values, current_index);
+                break;
+            }
+            direction = Direction::Increasing;
+        } else {
+            // Unknown is valid, so just check for equality with opposite direction
+            if direction == Direction::Increasing {
+                crate::log_trace!("Unstable: Decreasing changed to increasing");
+                result = attempt_dampening(allow_dampening, values, current_index);
+                break;
+            }
+            direction = Direction::Decreasing;
+        }
+
+        previous_value = current_value;
+    }
+
+    crate::log_trace!("Values: {:?} - {}", values, result);
+    result
+}
+
+// Tries removing every level in turn, kept to cross-check the dampening shortcuts above
+fn is_stable_naive(values : &[i32], allow_dampening : bool) -> bool {
+    if is_stable(values, false) {
+        return true;
+    }
+
+    allow_dampening && (0..values.len()).any(|remove_index| is_stable(&create_dampened_vec(values, remove_index), false))
+}
+
+fn load_report(line : &Line) -> GenericResult<Vec<i32>> {
+    let values : Vec<i32> = line.text.split_whitespace().map(|s| line.parse(s)).collect::<GenericResult<_>>()?;
+    if values.is_empty() {
+        return Err(line.error(GenericError::BasicError("Expected at least one level".to_string())));
+    }
+    Ok(values)
+}
+
+fn load_reports(input : &str) -> GenericResult<Vec<Vec<i32>>> {
+    parse_lines(input, |line| load_report(line).map_err(|e| e.with_hint("expected space separated levels")))
+}
+
+fn count_safe_reports(reports : &[Vec<i32>], allow_dampening : bool, is_safe : fn(&[i32], bool) -> bool) -> GenericResult<usize> {
+    let mut result : usize = 0;
+
+    for values in reports {
+        if is_safe(values, allow_dampening) {
+            result += 1;
+        }
+    }
+
+    Ok(result)
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Vec<i32>>;
+
+    fn metadata(&self) -> Metadata {
+        Metadata { year: 2024, day: 2, title: "Red-Nosed Reports" }
+    }
+
+    fn parse(&self, input: &str) -> GenericResult<Self::Input> {
+        load_reports(input)
+    }
+
+    fn part_1(&self, input: &Self::Input, context: &Context) -> GenericResult<Answer> {
+        count_safe_reports(input, context.param("dampening", false)?, is_stable).map(Answer::from)
+    }
+
+    fn part_2(&self, input: &Self::Input, context: &Context) -> GenericResult<Answer> {
+        count_safe_reports(input, context.param("dampening", true)?, is_stable).map(Answer::from)
+    }
+
+    fn variants(&self, _part: Part) -> &'static [&'static str] {
+        &["fast", "naive"]
+    }
+
+    fn params(&self) -> &'static [&'static str] {
+        &["dampening"]
+    }
+
+    fn solve_variant(&self, part: Part, _variant: &str, input: &Self::Input, context: &Context) -> GenericResult<Answer> {
+        count_safe_reports(input, context.param("dampening", part == Part::Two)?, is_stable_naive).map(Answer::from)
+    }
+}
+
+#[test]
+pub fn run_test_1() -> GenericResult<()> {
+    let reports = Day2.parse(&std::fs::read_to_string("data/2024/day2/example.txt")?)?;
+    assert_eq!(Day2.part_1(&reports, &Context::default())?, Answer::Unsigned(2));
+    Ok(())
+}
+
+#[test]
+pub fn run_test_2() -> GenericResult<()> {
+    let reports = Day2.parse(&std::fs::read_to_string("data/2024/day2/example.txt")?)?;
+    assert_eq!(Day2.part_2(&reports, &Context::default())?, Answer::Unsigned(9));
+    assert_eq!(Day2.solve(Part::Two, &reports, &Context::default().with_variant("naive"))?, Answer::Unsigned(9));
+    assert!(Day2.solve(Part::Two, &reports, &Context::default().with_variant("quick")).is_err());
+    Ok(())
+}
+
+#[test]
+pub fn run_test_malformed_input() {
+    assert!(matches!(Day2.parse("7 6 4\n1 2 -\n"), Err(GenericError::Located { line: 2, column: 5, .. })));
+    assert!(matches!(Day2.parse("7 6 4\n\n1 2 3\n"), Err(GenericError::Located { line: 2, column: 1, .. })));
+}
@@ -1,6 +1,7 @@
-use std::io::{BufRead, BufReader};
-
-use crate::utility::generic_error::GenericResult;
+use crate::runner::answer::Answer;
+use crate::runner::context::Context;
+use crate::runner::solution::{Metadata, Solution};
+use crate::utility::generic_error::{GenericError, GenericResult};
 
 #[derive(PartialEq, Eq, Debug, Clone)]
 enum Token {
@@ -27,8 +28,8 @@ struct StringTokenBuilder<'a>
 impl<'a> StringTokenBuilder<'a> {
     fn new(string_to_match : &'a str, token_to_output: Token) -> Self {
         StringTokenBuilder {
-            string_to_match : string_to_match,
-            token_to_output : token_to_output }
+            string_to_match,
+            token_to_output }
     }
 }
 
@@ -54,19 +55,19 @@ impl IntegerTokenBuilder {
 impl TokenBuilder for IntegerTokenBuilder {
     fn try_consume_characters(&self, characters : &str, token_output : &mut Vec<Token>) -> usize {
         let mut matched_so_far = 0;
-        let mut value_so_far = 0;
+        let mut value_so_far : Option<u32> = Some(0);
 
         for character in characters.chars() {
             if let Some(digit) = character.to_digit(10) {
-                value_so_far *= 10;
-                value_so_far += digit;
+                value_so_far = value_so_far.and_then(|value| value.checked_mul(10)?.checked_add(digit));
                 matched_so_far += 1;
             } else {
                 break;
             }
         }
 
-        if matched_so_far > 0 {
+        // A number too large to hold is still consumed, it just can't be part of a mul
+        if let Some(value_so_far) = value_so_far.filter(|_| matched_so_far > 0) {
             token_output.push(Token::Integer(value_so_far as usize));
         }
 
@@ -95,7 +96,7 @@ fn tokenise(input : &str, support_do_dont : bool) -> Vec<Token> {
 
     let mut remaining = input;
 
-    while remaining.len() > 0 {
+    while !remaining.is_empty() {
 
         let mut consumed = 0;
         for tokeniser in tokenisers.iter() {
@@ -107,25 +108,31 @@ fn tokenise(input : &str, support_do_dont : bool) -> Vec<Token> {
 
         if consumed > 0 {
             remaining = &remaining[consumed..];
-        } else {
-            result.push(Token::Unknown(remaining.chars().next().unwrap()));
-            remaining = &remaining[1..];
+        } else if let Some(character) = remaining.chars().next() {
+            result.push(Token::Unknown(character));
+            remaining = &remaining[character.len_utf8()..];
         }
     }
 
     result
 }
 
-fn calculate_output(input_path : &str, support_do_dont : bool) -> GenericResult<usize> {
-    let file_handle = std::fs::File::open(input_path)?;
-    let reader = BufReader::new(file_handle);
+fn load_program(input : &str) -> GenericResult<Vec<String>> {
+    let mut result = Vec::new();
+
+    for line in input.lines() {
+        result.push(line.to_string());
+    }
+
+    Ok(result)
+}
 
+fn calculate_output(program : &[String], support_do_dont : bool) -> GenericResult<usize> {
     let mut result : usize = 0;
 
     let mut enabled = true;
 
-    for line in reader.lines() {
-        let line = line?;
+    for line in program {
         let tokens = tokenise(line.as_str(), support_do_dont);
 
         for index in 0..tokens.len() {
@@ -137,10 +144,8 @@ fn calculate_output(input_path : &str, support_do_dont : bool) -> GenericResult<
                 , Token::Comma
                 , Token::Integer(value2)
                 , Token::CloseParen
-                , ..] => {
-                    if enabled {
-                        result += value1 * value2
-                    }
+                , ..] if enabled => {
+                    result = result.checked_add(value1 * value2).ok_or_else(|| GenericError::BasicError("Sum of the multiplications overflowed".to_string()))?;
                 }
                 [Token::Do, ..] => { enabled = true }
                 [Token::Dont, ..] => { enabled = false }
@@ -154,22 +159,53 @@ fn calculate_output(input_path : &str, support_do_dont : bool) -> GenericResult<
     Ok(result)
 }
 
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<String>;
+
+    fn metadata(&self) -> Metadata {
+        Metadata { year: 2024, day: 3, title: "Mull It Over" }
+    }
+
+    fn parse(&self, input: &str) -> GenericResult<Self::Input> {
+        load_program(input)
+    }
+
+    fn part_1(&self, input: &Self::Input, context: &Context) -> GenericResult<Answer> {
+        calculate_output(input, context.param("do_dont", false)?).map(Answer::from)
+    }
+
+    fn part_2(&self, input: &Self::Input, context: &Context) -> GenericResult<Answer> {
+        calculate_output(input, context.param("do_dont", true)?).map(Answer::from)
+    }
+
+    fn params(&self) -> &'static [&'static str] {
+        &["do_dont"]
+    }
+}
+
 #[test]
 pub fn run_test_1() -> GenericResult<()> {
-    assert_eq!(calculate_output("data/day3/example_part1.txt", false)?, 161);
+    let program = Day3.parse(&std::fs::read_to_string("data/2024/day3/example_part1.txt")?)?;
+    assert_eq!(Day3.part_1(&program, &Context::default())?, Answer::Unsigned(161));
     Ok(())
 }
 
 #[test]
 pub fn run_test_2() -> GenericResult<()> {
-    assert_eq!(calculate_output("data/day3/example_part2.txt", true)?, 48);
+    let program = Day3.parse(&std::fs::read_to_string("data/2024/day3/example_part2.txt")?)?;
+    assert_eq!(Day3.part_2(&program, &Context::default())?, Answer::Unsigned(48));
+    assert_eq!(Day3.part_2(&program, &Context::default().with_param("do_dont", "false"))?, Answer::Unsigned(161));
     Ok(())
 }
 
-pub fn run(input_path : &String) -> GenericResult<()> {
-    // Part 1
-    println!("Part one result: {}", calculate_output(&input_path, false).unwrap());
-    // Part 2
-    println!("Part two result: {}", calculate_output(&input_path, true).unwrap());
+#[test]
+pub fn run_test_malformed_input() -> GenericResult<()> {
+    let program = Day3.parse("mul(2,3)\u{e9}mul(1,\u{e9}2)\nmul(99999999999,2)mul(4,5)\n")?;
+    assert_eq!(Day3.part_1(&program, &Context::default())?, Answer::Unsigned(26));
+
+    let program = Day3.parse("mul(4294967295,4294967295)mul(4294967295,4294967295)\n")?;
+    assert!(Day3.part_1(&program, &Context::default()).is_err());
     Ok(())
-}
\ No newline at end of file
+}
@@ -0,0 +1,172 @@
+use crate::runner::answer::Answer;
+use crate::runner::context::Context;
+use crate::runner::solution::{Metadata, Solution};
+use crate::utility::generic_error::{GenericError, GenericResult};
+use crate::utility::input_lines::numbered_lines;
+
+fn load_grid(input : &str) -> GenericResult<(String, i64)> {
+    let mut width : i64 = 0;
+    let mut grid = String::new();
+
+    for line in numbered_lines(input) {
+        if line.number == 1 {
+            width = line.text.len() as i64;
+        } else if line.text.len() as i64 != width {
+            return Err(line.error(GenericError::BasicError(format!("Expected a row of width {}", width))));
+        }
+        grid.push_str(line.text);
+    }
+
+    if width == 0 {
+        return Err(GenericError::BasicError("Failed to get first line".to_string()));
+    }
+
+    Ok((grid, width))
+}
+
+fn get_value_at_index(grid : &str, index: (i64, i64), width : i64) -> Option<u8> {
+    let test_index = index.1 * width + index.0;
+
+    // Note test against width since that would wrap around to the next line
+    if test_index < 0 || (test_index >= grid.len() as i64)
+    || index.0 < 0 || index.0 >= width {
+        return None;
+    }
+
+    Some(grid.as_bytes()[test_index as usize])
+}
+
+fn check_for_word(grid: &str, search_chars : &str, initial_index : (i64, i64), offset : (i64, i64), width : i64) -> bool {
+    // Note 1 because we already know that the first character is at initial_index
+    for search_index in 1..(search_chars.len() as i64) {
+        let x_index = initial_index.0 + (search_index * offset.0);
+        let y_index = initial_index.1 + (search_index * offset.1);
+
+        if let Some(value_at_index) = get_value_at_index(grid, (x_index, y_index), width) {
+            if value_at_index == search_chars.as_bytes()[search_index as usize] {
+                // Match, continue to checking next character
+                continue;
+            }
+        }
+
+        // Mismatch
+        return false;
+    }
+
+    // Successfully matched all search chars 
+    true
+}
+
+fn part_1(grid : &str, stride : i64, search_word : &str) -> GenericResult<usize> {
+    let Some(first_char) = search_word.chars().next().filter(|_| search_word.is_ascii()) else {
+        return Err(GenericError::BasicError(format!("Search word '{}' must be non-empty ASCII", search_word)));
+    };
+
+    let mut result = 0;
+
+    let offsets_to_test = [
+        (0, -1), // Up
+        (0, 1), // Down
+        (-1, 0), // Left
+        (1, 0), // Right
+        (-1, -1), // Up Left
+        (-1, 1), // Up Right
+        (1, -1), // Down Left
+        (1, 1), // Down Right
+    ];
+
+    for index in grid.match_indices(first_char) {
+        let x_index = index.0 as i64 % stride;
+        let y_index = index.0 as i64 / stride;
+        for offset in offsets_to_test.iter() {
+            if check_for_word(grid, search_word, (x_index, y_index), *offset, stride) {
+                result += 1;
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+// offset passed should be one of the diagonal offsets, this checks it and its opposite
+fn check_for_diagonal_mas(grid: &str, initial_index : (i64, i64), offset : (i64, i64), width : i64) -> bool {
+    assert!(offset.0.abs() == 1 && offset.1.abs() == 1);
+
+    if let Some(value_at_initial_corner) = get_value_at_index(grid, (initial_index.0 + offset.0, initial_index.1 + offset.1), width) {
+        if value_at_initial_corner as char == 'M' {
+            if let Some(value_at_opposite_corner) = get_value_at_index(grid, (initial_index.0 - offset.0, initial_index.1 - offset.1), width) {
+                return value_at_opposite_corner as char == 'S';
+            }
+        } else if value_at_initial_corner as char == 'S' {
+            if let Some(value_at_opposite_corner) = get_value_at_index(grid, (initial_index.0 - offset.0, initial_index.1 - offset.1), width) {
+                return value_at_opposite_corner as char == 'M';
+            }
+        }
+    }
+
+    false
+}
+
+fn part_2(grid : &str, stride : i64) -> GenericResult<usize> {
+    let mut result = 0;
+
+    for index in grid.match_indices('A') {
+        let x_index = index.0 as i64 % stride;
+        let y_index = index.0 as i64 / stride;
+
+        if check_for_diagonal_mas(grid, (x_index, y_index), (-1,-1), stride)
+            && check_for_diagonal_mas(grid, (x_index, y_index), (-1,1), stride) {
+                result += 1;
+            }
+    }
+
+    Ok(result)
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = (String, i64);
+
+    fn metadata(&self) -> Metadata {
+        Metadata { year: 2024, day: 4, title: "Ceres Search" }
+    }
+
+    fn parse(&self, input: &str) -> GenericResult<Self::Input> {
+        load_grid(input)
+    }
+
+    fn part_1(&self, input: &Self::Input, context: &Context) -> GenericResult<Answer> {
+        part_1(&input.0, input.1, &context.param("word", "XMAS".to_string())?).map(Answer::from)
+    }
+
+    fn part_2(&self, input: &Self::Input, _context: &Context) -> GenericResult<Answer> {
+        part_2(&input.0, input.1).map(Answer::from)
+    }
+
+    fn params(&self) -> &'static [&'static str] {
+        &["word"]
+    }
+}
+
+#[test]
+pub fn run_test_1() -> GenericResult<()> {
+    let grid = Day4.parse(&std::fs::read_to_string("data/2024/day4/example_part1.txt")?)?;
+    assert_eq!(Day4.part_1(&grid, &Context::default())?, Answer::Unsigned(18));
+    assert_eq!(Day4.part_1(&grid, &Context::default().with_param("word", "SAMX"))?, Answer::Unsigned(18));
+    assert!(Day4.part_1(&grid, &Context::default().with_param("word", "")).is_err());
+    Ok(())
+}
+
+#[test]
+pub fn run_test_2() -> GenericResult<()> {
+    let grid = Day4.parse(&std::fs::read_to_string("data/2024/day4/example_part2.txt")?)?;
+    assert_eq!(Day4.part_2(&grid, &Context::default())?, Answer::Unsigned(9));
+    Ok(())
+}
+
+#[test]
+pub fn run_test_malformed_input() {
+    assert!(matches!(Day4.parse("XMAS\nSAM\nXMAS\n"), Err(GenericError::Located { line: 2, column: 1, .. })));
+    assert!(Day4.parse("").is_err());
+}
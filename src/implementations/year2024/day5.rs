@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+
+use crate::runner::answer::Answer;
+use crate::runner::context::Context;
+use crate::runner::solution::{Metadata, Solution};
+use crate::utility::generic_error::{GenericError, GenericResult};
+use crate::utility::input_lines::{numbered_lines, Line};
+
+#[derive(Default, Debug, Clone)]
+pub struct OrderingRuleEntry {
+    after_this : Vec<usize>,
+}
+
+type OrderingRules = HashMap<usize, OrderingRuleEntry>;
+type UpdateList = Vec<Vec<usize>>;
+
+fn load_ordering_rules<'a>(lines : &mut impl Iterator<Item = Line<'a>>) -> GenericResult<OrderingRules> {
+    let mut result : OrderingRules = HashMap::new();
+
+    for line in lines {
+        if line.text.is_empty() {
+            // Reached the end of this section
+            break;
+        }
+
+        let parsed = sscanf::sscanf!(line.text.trim(), "{}|{}", usize, usize).map_err(|e| line.error(e).with_hint("expected `N|M` in the ordering rules section"))?;
+        let entry = result.entry(parsed.0).or_default();
+        entry.after_this.push(parsed.1);
+    }
+
+    Ok(result)
+}
+
+fn load_update_list<'a>(lines : &mut impl Iterator<Item = Line<'a>>) -> GenericResult<UpdateList> {
+    let mut result : UpdateList = Vec::new();
+
+    for line in lines {
+        let split_line : Vec<usize> = line.text.trim().split(',').map(|x| line.parse(x)).collect::<GenericResult<_>>()
+            .map_err(|e| e.with_hint("expected comma separated page numbers in the updates section"))?;
+        // Both parts need a middle page
+        if split_line.len().is_multiple_of(2) {
+            return Err(line.error(GenericError::BasicError("Expected an odd number of pages".to_string())));
+        }
+        result.push(split_line);
+    }
+    Ok(result)
+}
+
+fn load_document(input : &str) -> GenericResult<(OrderingRules, UpdateList)> {
+    let mut lines = numbered_lines(input);
+
+    let ordering_rules = load_ordering_rules(&mut lines)?;
+    let update_list = load_update_list(&mut lines)?;
+
+
+    Ok((ordering_rules, update_list))
+}
+
+fn is_valid_update(ordering_rules: &OrderingRules, update: &[usize]) -> bool {
+    if update.len() <= 1 {
+        return true;
+    }
+
+    for (entry_index, entry_value) in update.iter().enumerate() {
+        for test_entry in &update[0..entry_index] {
+            if let Some(entry_rules) = ordering_rules.get(entry_value) {
+                if entry_rules.after_this.contains(test_entry) {
+                    return false;
+                }
+            }
+        }
+    }
+
+    true
+}
+
+fn part_1(ordering_rules : &OrderingRules, update_list : &UpdateList) -> GenericResult<usize> {
+    let mut result = 0;
+
+    for update in update_list {
+        if is_valid_update(ordering_rules, update) {
+            result += update[update.len() / 2];
+        }
+    }
+
+    Ok(result)
+}
+
+fn part_2(ordering_rules : &OrderingRules, update_list : &UpdateList) -> GenericResult<usize> {
+    let mut result = 0;
+
+    for update in update_list {
+        if !is_valid_update(ordering_rules, update) {
+            let mut update = update.clone();
+
+            // Apparently the ordering graph in not acyclic across all entries,
+            // and is only guaranteed to be resolvable for particular given updates
+            // So fuck it, we're doing a slow sort
+            for _ in 0..update.len() {
+                for i in 1..update.len() {
+                    if let Some(i_ordering) = ordering_rules.get(&update[i]) {
+                        if i_ordering.after_this.contains(&update[i-1]) {
+                            update.swap(i-1, i);
+                        }
+                    }
+                }
+            }
+            result += update[update.len() / 2];
+        }
+    }
+
+    Ok(result)
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = (OrderingRules, UpdateList);
+
+    fn metadata(&self) -> Metadata {
+        Metadata { year: 2024, day: 5, title: "Print Queue" }
+    }
+
+    fn parse(&self, input: &str) -> GenericResult<Self::Input> {
+        load_document(input)
+    }
+
+    fn part_1(&self, input: &Self::Input, _context: &Context) -> GenericResult<Answer> {
+        part_1(&input.0, &input.1).map(Answer::from)
+    }
+
+    fn part_2(&self, input: &Self::Input, _context: &Context) -> GenericResult<Answer> {
+        part_2(&input.0, &input.1).map(Answer::from)
+    }
+}
+
+#[test]
+pub fn run_test_1() -> GenericResult<()> {
+    let document = Day5.parse(&std::fs::read_to_string("data/2024/day5/example.txt")?)?;
+    assert_eq!(Day5.part_1(&document, &Context::default())?, Answer::Unsigned(143));
+    Ok(())
+}
+
+#[test]
+pub fn run_test_2() -> GenericResult<()> {
+    let document = Day5.parse(&std::fs::read_to_string("data/2024/day5/example.txt")?)?;
+    assert_eq!(Day5.part_2(&document, &Context::default())?, Answer::Unsigned(123));
+    Ok(())
+}
+
+#[test]
+pub fn run_test_located_errors() {
+    assert!(matches!(Day5.parse("47|53\n97-13\n\n75,47,61\n"), Err(GenericError::Located { line: 2, column: 1, .. })));
+    assert!(matches!(Day5.parse("47|53\n\n75,47,61\n75,4x,53\n"), Err(GenericError::Located { line: 4, column: 4, .. })));
+    assert!(matches!(Day5.parse("47|53\n\n75,47,61\n75,47\n"), Err(GenericError::Located { line: 4, column: 1, .. })));
+}
+
+#[test]
+pub fn run_test_hints() {
+    let error = Day5.parse("47|53\n97-13\n").unwrap_err();
+    assert!(matches!(error, GenericError::Located { length: 5, hint: Some(ref hint), .. } if hint == "expected `N|M` in the ordering rules section"));
+}
@@ -1,17 +1,19 @@
-use std::io::{BufRead, BufReader};
-
+use crate::runner::answer::Answer;
+use crate::runner::context::Context;
+use crate::runner::solution::{Metadata, Solution};
 use crate::utility::generic_error::{GenericError, GenericResult};
+use crate::utility::input_lines::numbered_lines;
 
-#[derive(Clone, Copy)]
-enum Direction {
+#[derive(Clone, Copy, Debug)]
+pub enum Direction {
     Up,
     Down,
     Left,
     Right,
 }
 
-#[derive(Debug)]
-struct Grid {
+#[derive(Clone, Debug)]
+pub struct Grid {
     blockages: Vec<bool>,
     width: i64,
 }
@@ -20,7 +22,7 @@ type Coordinates = (i64, i64);
 
 impl Grid {
     fn convert_coordinates_to_index(&self, coordinates : Coordinates) -> i64 {
-        return coordinates.1 * self.width + coordinates.0;
+        coordinates.1 * self.width + coordinates.0
     }
 
     fn is_blocked(&self, index: Coordinates) -> Option<bool> {
@@ -36,41 +38,39 @@ impl Grid {
     }
 }
 
-fn load_grid(input_path : &str) -> GenericResult<(Grid, Coordinates, Direction)> {
-    let file_handle = std::fs::File::open(input_path)?;
-    let reader = BufReader::new(file_handle);
-
+fn load_grid(input : &str) -> GenericResult<(Grid, Coordinates, Direction)> {
     let mut width : i64 = 0;
-    let mut peekable_lines = reader.lines().peekable();
-    if let Some(Ok(first_line)) = peekable_lines.peek() {
-        width = first_line.len() as i64;
-    }
-    
-    if width == 0 {
-        return Err(GenericError::BasicError("Failed to get first line".to_string()));
-    }
-
     let mut blockages: Vec<bool> = Vec::new();
     let mut guard_location : Coordinates = (-1, -1);
     let mut guard_facing : Direction = Direction::Up;
 
-    for (y_index,line) in peekable_lines.enumerate() {
-        let line = line?;
-        for (x_index, character) in line.chars().enumerate() {
+    for line in numbered_lines(input) {
+        let y_index = (line.number - 1) as i64;
+        if line.number == 1 {
+            width = line.text.len() as i64;
+        } else if line.text.len() as i64 != width {
+            return Err(line.error(GenericError::BasicError(format!("Expected a row of width {}", width))));
+        }
+
+        for (x_index, character) in line.text.char_indices() {
             let mut is_blocked = false;
             match character {
                 '.' => {}
                 '#' => { is_blocked = true; }
-                '^' => { guard_location = (x_index as i64, y_index as i64); guard_facing = Direction::Up; }
-                'v' => { guard_location = (x_index as i64, y_index as i64); guard_facing = Direction::Down; }
-                '<' => { guard_location = (x_index as i64, y_index as i64); guard_facing = Direction::Left; }
-                '>' => { guard_location = (x_index as i64, y_index as i64); guard_facing = Direction::Right; }
-                _ => { assert!(false, "Invalid character {}", character); }
+                '^' => { guard_location = (x_index as i64, y_index); guard_facing = Direction::Up; }
+                'v' => { guard_location = (x_index as i64, y_index); guard_facing = Direction::Down; }
+                '<' => { guard_location = (x_index as i64, y_index); guard_facing = Direction::Left; }
+                '>' => { guard_location = (x_index as i64, y_index); guard_facing = Direction::Right; }
+                _ => { return Err(line.error_at(&line.text[x_index..x_index + character.len_utf8()], GenericError::BasicError(format!("Invalid character '{}'", character)))); }
             }
             blockages.push(is_blocked);
         }
     }
 
+    if width == 0 {
+        return Err(GenericError::BasicError("Failed to get first line".to_string()));
+    }
+
     if guard_location == (-1,-1) {
         return Err(GenericError::BasicError("Did not find guard location".to_string()));
     }
@@ -80,26 +80,24 @@ fn load_grid(input_path : &str) -> GenericResult<(Grid, Coordinates, Direction)>
 
 fn coordinate_in_direction(coordinate: &Coordinates, direction: &Direction) -> Coordinates {
     match direction {
-        Direction::Up => return (coordinate.0, coordinate.1 - 1),
-        Direction::Down => return (coordinate.0, coordinate.1 + 1),
-        Direction::Left => return (coordinate.0 - 1, coordinate.1),
-        Direction::Right => return (coordinate.0 + 1, coordinate.1),
+        Direction::Up => (coordinate.0, coordinate.1 - 1),
+        Direction::Down => (coordinate.0, coordinate.1 + 1),
+        Direction::Left => (coordinate.0 - 1, coordinate.1),
+        Direction::Right => (coordinate.0 + 1, coordinate.1),
     }
 }
 
 fn turn_right(direction: &Direction) -> Direction {
     match direction {
-        Direction::Up => return Direction::Right,
-        Direction::Down => return Direction::Left,
-        Direction::Left => return Direction::Up,
-        Direction::Right => return Direction::Down,
+        Direction::Up => Direction::Right,
+        Direction::Down => Direction::Left,
+        Direction::Left => Direction::Up,
+        Direction::Right => Direction::Down,
     }
 }
 
-fn part_1(input_path : &str) -> GenericResult<usize> {
-    let (grid, mut guard_location, mut guard_facing) = load_grid(input_path)?;
-
-    //println!("Grid: {:?}", grid);
+fn part_1(grid : &Grid, mut guard_location : Coordinates, mut guard_facing : Direction) -> GenericResult<usize> {
+    crate::log_trace!("Grid: {:?}", grid);
 
     let mut visited : Vec<bool> = Vec::new();
     visited.resize(grid.blockages.len(), false);
@@ -123,7 +121,7 @@ fn part_1(input_path : &str) -> GenericResult<usize> {
     }
 
     let result = visited.iter().filter(|s| **s).count();
-    //println!("Visited: {:?}", visited);
+    crate::log_trace!("Visited: {:?}", visited);
     Ok(result)
 }
 
@@ -158,10 +156,14 @@ fn does_infinite_loop(grid: &Grid, mut visited:Vec<bool>, start_location:Coordin
     false
 }
 
-fn part_2(input_path : &str) -> GenericResult<usize> {
+fn part_2(grid : &Grid, mut guard_location : Coordinates, mut guard_facing : Direction, context : &Context) -> GenericResult<usize> {
     let mut result : Vec<Coordinates> = Vec::new();
 
-    let (mut grid, mut guard_location, mut guard_facing) = load_grid(input_path)?;
+    // Every tile on the original path except the start gets tested as an obstacle once
+    let candidates = part_1(grid, guard_location, guard_facing)? - 1;
+    let mut candidates_tested = 0;
+
+    let mut grid = grid.clone();
     let grid_size = grid.blockages.len();
 
     let mut visited : Vec<bool> = Vec::new();
@@ -186,12 +188,13 @@ fn part_2(input_path : &str) -> GenericResult<usize> {
                 
                     let test_added_blockage_index = grid.convert_coordinates_to_index(new_location) as usize;
                     grid.blockages[test_added_blockage_index] = true;
-                    if does_infinite_loop(&grid, visited.clone(), guard_location, right_direction) {
-                        if !result.contains(&new_location) {
-                            result.push(new_location);
-                        }
+                    if does_infinite_loop(&grid, visited.clone(), guard_location, right_direction) && !result.contains(&new_location) {
+                        result.push(new_location);
                     }
                     grid.blockages[test_added_blockage_index] = false;
+
+                    candidates_tested += 1;
+                    context.progress(candidates_tested, candidates);
                 }
 
                 guard_location = new_location;
@@ -204,26 +207,53 @@ fn part_2(input_path : &str) -> GenericResult<usize> {
         }
     }
 
-    //println!("Visited: {:?}", visited);
+    crate::log_trace!("Visited: {:?}", visited);
     Ok(result.len())
 }
 
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = (Grid, Coordinates, Direction);
+
+    fn metadata(&self) -> Metadata {
+        Metadata { year: 2024, day: 6, title: "Guard Gallivant" }
+    }
+
+    fn parse(&self, input: &str) -> GenericResult<Self::Input> {
+        load_grid(input)
+    }
+
+    fn part_1(&self, input: &Self::Input, _context: &Context) -> GenericResult<Answer> {
+        part_1(&input.0, input.1, input.2).map(Answer::from)
+    }
+
+    fn part_2(&self, input: &Self::Input, context: &Context) -> GenericResult<Answer> {
+        part_2(&input.0, input.1, input.2, context).map(Answer::from)
+    }
+}
+
 #[test]
 pub fn run_test_1() -> GenericResult<()> {
-    assert_eq!(part_1("data/day6/example.txt")?, 41);
+    let grid = Day6.parse(&std::fs::read_to_string("data/2024/day6/example.txt")?)?;
+    assert_eq!(Day6.part_1(&grid, &Context::default())?, Answer::Unsigned(41));
     Ok(())
 }
 
 #[test]
 pub fn run_test_2() -> GenericResult<()> {
-    assert_eq!(part_2("data/day6/example.txt")?, 7);
+    let grid = Day6.parse(&std::fs::read_to_string("data/2024/day6/example.txt")?)?;
+    assert_eq!(Day6.part_2(&grid, &Context::default())?, Answer::Unsigned(7));
+
+    let progress = std::sync::Arc::new(crate::runner::progress::RecordingProgress::default());
+    Day6.part_2(&grid, &Context::default().with_progress(progress.clone()))?;
+    assert_eq!(progress.updates.lock().unwrap().last(), Some(&(40, 40)));
     Ok(())
 }
 
-pub fn run(input_path : &String) -> GenericResult<()> {
-    // Part 1
-    println!("Part one result: {}", part_1(&input_path).unwrap());
-    // Part 2
-    println!("Part two result: {}", part_2(&input_path).unwrap());
-    Ok(())
-}
\ No newline at end of file
+#[test]
+pub fn run_test_malformed_input() {
+    assert!(matches!(Day6.parse("..#.\n.^x.\n"), Err(GenericError::Located { line: 2, column: 3, length: 1, .. })));
+    assert!(matches!(Day6.parse("..#.\n.^.\n"), Err(GenericError::Located { line: 2, column: 1, .. })));
+    assert!(Day6.parse("..#.\n....\n").is_err());
+}
@@ -0,0 +1,160 @@
+use crate::runner::answer::Answer;
+use crate::runner::context::Context;
+use crate::runner::solution::{Metadata, Solution};
+use crate::utility::generic_error::{GenericError, GenericResult};
+use crate::utility::input_lines::{parse_lines, Line};
+
+#[derive(Debug)]
+pub struct Equation {
+    expected_result: usize,
+    equation_elements: Vec<usize>,
+}
+
+fn load_equation(line : &Line) -> GenericResult<Equation> {
+    let Some((expected_result, elements)) = line.text.split_once(':') else {
+        return Err(line.error(GenericError::BasicError("Expected a ':' after the test value".to_string())));
+    };
+    let expected_result = line.parse(expected_result.trim())?;
+    let equation_elements :Vec<usize> = elements.split_whitespace().map(|s| line.parse(s)).collect::<GenericResult<_>>()?;
+    if equation_elements.is_empty() {
+        return Err(line.error_at(elements, GenericError::BasicError("Expected at least one number after the ':'".to_string())));
+    }
+    Ok(Equation{expected_result, equation_elements})
+}
+
+fn load_equations(input : &str) -> GenericResult<Vec<Equation>> {
+    parse_lines(input, |line| load_equation(line).map_err(|e| e.with_hint("expected `RESULT: N N ...`")))
+}
+
+fn recursive_fn_part1(test_result: usize, result_so_far:usize, remaining_components: &[usize]) -> bool {
+    if remaining_components.is_empty() {
+        return result_so_far == test_result;
+    }
+
+    if result_so_far > test_result {
+        return false;
+    }
+
+    // An overflowing result is bigger than any target, so that branch can't reach it
+    result_so_far.checked_add(remaining_components[0]).is_some_and(|value| recursive_fn_part1(test_result, value, &remaining_components[1..]))
+        || result_so_far.checked_mul(remaining_components[0]).is_some_and(|value| recursive_fn_part1(test_result, value, &remaining_components[1..]))
+}
+
+fn checked_total(result : usize, expected_result : usize) -> GenericResult<usize> {
+    result.checked_add(expected_result).ok_or_else(|| GenericError::BasicError("Total calibration result overflowed".to_string()))
+}
+
+fn part_1(equations : &[Equation], context : &Context) -> GenericResult<usize> {
+    let mut result = 0;
+    for (index, equation) in equations.iter().enumerate() {
+        if recursive_fn_part1(equation.expected_result, equation.equation_elements[0], &equation.equation_elements[1..]) {
+            result = checked_total(result, equation.expected_result)?;
+        }
+        context.progress(index + 1, equations.len());
+    }
+
+    Ok(result)
+}
+
+// The digits of left followed by those of right, None if that doesn't fit
+fn concatenate(left: usize, right: usize) -> Option<usize> {
+    let digits = right.checked_ilog10().unwrap_or(0) + 1;
+    left.checked_mul(10usize.checked_pow(digits)?)?.checked_add(right)
+}
+
+fn recursive_fn_part2(test_result: usize, result_so_far:usize, remaining_components: &[usize]) -> bool {
+    if remaining_components.is_empty() {
+        return result_so_far == test_result;
+    }
+
+    if result_so_far > test_result {
+        return false;
+    }
+
+    concatenate(result_so_far, remaining_components[0]).is_some_and(|value| recursive_fn_part2(test_result, value, &remaining_components[1..]))
+        || result_so_far.checked_add(remaining_components[0]).is_some_and(|value| recursive_fn_part2(test_result, value, &remaining_components[1..]))
+        || result_so_far.checked_mul(remaining_components[0]).is_some_and(|value| recursive_fn_part2(test_result, value, &remaining_components[1..]))
+}
+
+fn part_2(equations : &[Equation], context : &Context) -> GenericResult<usize> {
+    let mut result = 0;
+    for (index, equation) in equations.iter().enumerate() {
+        if recursive_fn_part2(equation.expected_result, equation.equation_elements[0], &equation.equation_elements[1..]) {
+            result = checked_total(result, equation.expected_result)?;
+        }
+        context.progress(index + 1, equations.len());
+    }
+
+    Ok(result)
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<Equation>;
+
+    fn metadata(&self) -> Metadata {
+        Metadata { year: 2024, day: 7, title: "Bridge Repair" }
+    }
+
+    fn parse(&self, input: &str) -> GenericResult<Self::Input> {
+        load_equations(input)
+    }
+
+    fn part_1(&self, input: &Self::Input, context: &Context) -> GenericResult<Answer> {
+        part_1(input, context).map(Answer::from)
+    }
+
+    fn part_2(&self, input: &Self::Input, context: &Context) -> GenericResult<Answer> {
+        part_2(input, context).map(Answer::from)
+    }
+}
+
+#[test]
+pub fn run_test_1() -> GenericResult<()> {
+    let equations = Day7.parse(&std::fs::read_to_string("data/2024/day7/example.txt")?)?;
+    assert_eq!(Day7.part_1(&equations, &Context::default())?, Answer::Unsigned(3749));
+    Ok(())
+}
+
+#[test]
+pub fn run_test_2() -> GenericResult<()> {
+    let equations = Day7.parse(&std::fs::read_to_string("data/2024/day7/example.txt")?)?;
+    assert_eq!(Day7.part_2(&equations, &Context::default())?, Answer::Unsigned(11387));
+
+    let progress = std::sync::Arc::new(crate::runner::progress::RecordingProgress::default());
+    Day7.part_2(&equations, &Context::default().with_progress(progress.clone()))?;
+    let updates = progress.updates.lock().unwrap();
+    assert_eq!(updates.len(), equations.len());
+    assert_eq!(updates.last(), Some(&(equations.len(), equations.len())));
+    Ok(())
+}
+
+#[test]
+pub fn run_test_located_errors() {
+    let error = Day7.parse("190: 10 19\n3267: 81 4O 27\n").unwrap_err();
+    assert_eq!(error.with_source("input.txt").to_string(), "input.txt:2:10: invalid integer: invalid digit found in string");
+
+    assert!(matches!(Day7.parse("190 10 19\n"), Err(GenericError::Located { line: 1, column: 1, .. })));
+    assert!(matches!(Day7.parse("190:\n"), Err(GenericError::Located { line: 1, column: 5, .. })));
+}
+
+#[test]
+pub fn run_test_overflow() -> GenericResult<()> {
+    assert_eq!(concatenate(15, 6), Some(156));
+    assert_eq!(concatenate(1, 0), Some(10));
+
+    let equations = Day7.parse("18446744073709551615: 4294967296 4294967296\n")?;
+    assert_eq!(Day7.part_1(&equations, &Context::default())?, Answer::Unsigned(0));
+    assert_eq!(Day7.part_2(&equations, &Context::default())?, Answer::Unsigned(0));
+
+    let equations = Day7.parse("18446744073709551615: 1844674407370955161 6\n")?;
+    assert_eq!(Day7.part_2(&equations, &Context::default())?, Answer::Unsigned(0));
+
+    let equations = Day7.parse("18446744073709551615: 1844674407370955161 5\n")?;
+    assert_eq!(Day7.part_2(&equations, &Context::default())?, Answer::Unsigned(u64::MAX));
+
+    let equations = Day7.parse("18446744073709551615: 18446744073709551615\n18446744073709551615: 18446744073709551615\n")?;
+    assert!(Day7.part_1(&equations, &Context::default()).is_err());
+    Ok(())
+}
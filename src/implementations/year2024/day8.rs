@@ -0,0 +1,170 @@
+use std::collections::HashMap;
+
+use crate::runner::answer::Answer;
+use crate::runner::context::Context;
+use crate::runner::solution::{Metadata, Solution};
+use crate::utility::generic_error::{GenericError, GenericResult};
+use crate::utility::grid_2d::{self, Coordinates};
+use crate::utility::input_lines::numbered_lines;
+use crate::utility::log;
+
+type AntennaMap = HashMap<char, Vec<grid_2d::Coordinates>>;
+type AntinodeGrid = grid_2d::Grid<bool>;
+
+fn load_antennas(input : &str) -> GenericResult<(AntennaMap, usize, usize)> {
+    let mut result: AntennaMap = HashMap::new();
+    let mut width = 0;
+    let mut height= 0;
+
+    for line in numbered_lines(input) {
+        let y_index = line.number - 1;
+        height += 1;
+        if line.number > 1 && line.text.len() != width {
+            return Err(line.error(GenericError::BasicError(format!("Expected a row of width {}", width))));
+        }
+        width = line.text.len();
+        
+        for (x_index, character) in line.text.chars().enumerate() {
+            if character == '.' {
+                continue;
+            }
+
+            result.entry(character).or_default().push(grid_2d::Coordinates{x: x_index as i64, y: y_index as i64});
+        }
+    }
+
+    if width == 0 {
+        return Err(GenericError::BasicError("Failed to get first line".to_string()));
+    }
+
+    Ok((result, width, height))
+}
+
+fn generate_antinodes_part1(nodes_of_same_type: &[Coordinates], antinode_grid: &mut AntinodeGrid) {
+    for i in 1..nodes_of_same_type.len() {
+        for j in 0..i {
+            let antinode_coords_1 = (nodes_of_same_type[i] * 2) - nodes_of_same_type[j];
+            antinode_grid.set_value(antinode_coords_1, true);
+
+            let antinode_coords_2 = (nodes_of_same_type[j] * 2) - nodes_of_same_type[i];
+            antinode_grid.set_value(antinode_coords_2, true);
+        }
+    }
+}
+
+fn print_antinodes(antinode_grid: &AntinodeGrid) {
+    if !log::enabled(log::Level::Debug) {
+        return;
+    }
+
+    for i in 0..antinode_grid.num_rows() {
+        let mut row_str = String::new();
+        for j in 0..antinode_grid.num_columns() {
+            if let Some(value) = antinode_grid.get_value(Coordinates { x: j as i64, y: i as i64 }) {
+                if *value {
+                    row_str.push('#');
+                } else {
+                    row_str.push('.');
+                }
+            }
+        }
+        crate::log_debug!("{}", row_str);
+    }
+}
+
+fn part_1(antennas : &AntennaMap, width : usize, height : usize) -> GenericResult<usize> {
+    let mut antinode_grid: AntinodeGrid = AntinodeGrid::new(width, height, false);
+
+    for antenna_type in antennas {
+        generate_antinodes_part1(antenna_type.1, &mut antinode_grid);
+    }
+    print_antinodes(&antinode_grid);
+
+    let mut result = 0;
+    for antinode_value in antinode_grid.iter() {
+        if *antinode_value {
+            result += 1;
+        }
+    }
+
+    Ok(result)
+}
+
+fn generate_antinodes_part2(nodes_of_same_type: &[Coordinates], antinode_grid: &mut AntinodeGrid) {
+    for i in 1..nodes_of_same_type.len() {
+        for j in 0..i {
+            let diff = nodes_of_same_type[i] - nodes_of_same_type[j];
+
+            let mut current_antinode_coords = nodes_of_same_type[i];
+            while antinode_grid.set_value(current_antinode_coords, true) {
+                current_antinode_coords += diff;
+            }
+            
+            current_antinode_coords = nodes_of_same_type[j];
+            while antinode_grid.set_value(current_antinode_coords, true) {
+                current_antinode_coords -= diff;
+            }
+        }
+    }
+}
+
+fn part_2(antennas : &AntennaMap, width : usize, height : usize) -> GenericResult<usize> {
+    let mut antinode_grid: AntinodeGrid = AntinodeGrid::new(width, height, false);
+
+    for antenna_type in antennas {
+        generate_antinodes_part2(antenna_type.1, &mut antinode_grid);
+    }
+    print_antinodes(&antinode_grid);
+
+    let mut result = 0;
+    for antinode_value in antinode_grid.iter() {
+        if *antinode_value {
+            result += 1;
+        }
+    }
+
+    Ok(result)
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = (AntennaMap, usize, usize);
+
+    fn metadata(&self) -> Metadata {
+        Metadata { year: 2024, day: 8, title: "Resonant Collinearity" }
+    }
+
+    fn parse(&self, input: &str) -> GenericResult<Self::Input> {
+        load_antennas(input)
+    }
+
+    fn part_1(&self, input: &Self::Input, _context: &Context) -> GenericResult<Answer> {
+        part_1(&input.0, input.1, input.2).map(Answer::from)
+    }
+
+    fn part_2(&self, input: &Self::Input, _context: &Context) -> GenericResult<Answer> {
+        part_2(&input.0, input.1, input.2).map(Answer::from)
+    }
+}
+
+#[test]
+pub fn run_test_1() -> GenericResult<()> {
+    let antennas = Day8.parse(&std::fs::read_to_string("data/2024/day8/example.txt")?)?;
+    assert_eq!(Day8.part_1(&antennas, &Context::default())?, Answer::Unsigned(14));
+    Ok(())
+}
+
+#[test]
+pub fn run_test_2() -> GenericResult<()> {
+    let antennas = Day8.parse(&std::fs::read_to_string("data/2024/day8/example.txt")?)?;
+    assert_eq!(Day8.part_2(&antennas, &Context::default())?, Answer::Unsigned(34));
+    Ok(())
+}
+
+#[test]
+pub fn run_test_malformed_input() {
+    assert!(matches!(Day8.parse("..a.\n...\n.a..\n"), Err(GenericError::Located { line: 2, column: 1, .. })));
+    assert!(Day8.parse("").is_err());
+    assert!(Day8.parse("\n\n").is_err());
+}
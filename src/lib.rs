@@ -0,0 +1,27 @@
+pub mod implementations;
+pub mod runner;
+pub mod utility;
+
+pub use runner::answer::Answer;
+pub use runner::solution::Part;
+use utility::generic_error::GenericResult;
+
+// Solves one part of a day from the default year, input is the puzzle input's content
+pub fn solve(day: u16, part: Part, input: &str) -> GenericResult<Answer> {
+    solve_year(runner::DEFAULT_YEAR, day, part, input)
+}
+
+pub fn solve_year(year: u16, day: u16, part: Part, input: &str) -> GenericResult<Answer> {
+    let solution = implementations::registry().find(year, day)?;
+    let parsed = solution.parse(input)?;
+    solution.solve(part, parsed.as_ref(), &runner::context::Context::default())
+}
+
+#[test]
+pub fn run_test_solve() -> GenericResult<()> {
+    let input = std::fs::read_to_string("data/2024/day1/example.txt")?;
+    assert_eq!(solve(1, Part::One, &input)?, Answer::Unsigned(11));
+    assert_eq!(solve_year(2024, 1, Part::Two, &input)?, Answer::Unsigned(31));
+    assert!(solve(26, Part::One, &input).is_err());
+    Ok(())
+}
@@ -0,0 +1,128 @@
+use std::convert::Infallible;
+use std::fmt::{Display, Formatter, Error as FmtError};
+use std::str::FromStr;
+
+#[derive(Clone, Debug)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    Text(String),
+    // Usually ASCII art read off a grid, printed starting on its own line
+    MultiLine(String),
+}
+
+impl Answer {
+    pub fn is_multi_line(&self) -> bool {
+        matches!(self, Answer::MultiLine(_))
+    }
+
+    pub fn as_integer(&self) -> Option<i128> {
+        match self {
+            Answer::Unsigned(value) => Some(*value as i128),
+            Answer::Signed(value) => Some(*value as i128),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), FmtError> {
+        match self {
+            Answer::Unsigned(value) => write!(f, "{}", value),
+            Answer::Signed(value) => write!(f, "{}", value),
+            Answer::Text(text) | Answer::MultiLine(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+// Numbers compare by value, so a signed 11 is the same answer as an unsigned 11
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Unsigned(a), Answer::Unsigned(b)) => a == b,
+            (Answer::Signed(a), Answer::Signed(b)) => a == b,
+            (Answer::Unsigned(a), Answer::Signed(b)) | (Answer::Signed(b), Answer::Unsigned(a)) => i64::try_from(*a).is_ok_and(|a| a == *b),
+            (Answer::Text(a), Answer::Text(b)) | (Answer::MultiLine(a), Answer::MultiLine(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+// Reads back an answer that was written with Display, e.g. from an answers file
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.contains('\n') {
+            Ok(Answer::MultiLine(s.to_string()))
+        } else if let Ok(value) = s.parse() {
+            Ok(Answer::Unsigned(value))
+        } else if let Ok(value) = s.parse() {
+            Ok(Answer::Signed(value))
+        } else {
+            Ok(Answer::Text(s.to_string()))
+        }
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Unsigned(value as u64)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Unsigned(value)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::Unsigned(value as u64)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Signed(value)
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Answer::Signed(value as i64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+#[test]
+pub fn run_test_answer() {
+    assert_eq!(Answer::Unsigned(11), Answer::Signed(11));
+    assert_ne!(Answer::Unsigned(11), Answer::Signed(-11));
+    assert_ne!(Answer::Unsigned(11), Answer::Text("11".to_string()));
+    assert_eq!(Answer::Signed(-3).to_string(), "-3");
+
+    for answer in [Answer::Unsigned(u64::MAX), Answer::Signed(-42), Answer::from("6,0,4"), Answer::MultiLine("#..#\n####".to_string())] {
+        assert_eq!(answer.to_string().parse::<Answer>(), Ok(answer));
+    }
+
+    // Parsing guesses the type, so text that looks like a number only survives as a string
+    let text = Answer::from("123");
+    assert_eq!(text.to_string(), "123");
+    assert_ne!(text.to_string().parse::<Answer>(), Ok(text));
+    assert_eq!(Answer::from("007").to_string().parse::<Answer>(), Ok(Answer::Unsigned(7)));
+}
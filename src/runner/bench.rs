@@ -0,0 +1,146 @@
+use std::time::{Duration, Instant};
+
+use crate::runner::context::Context;
+use crate::runner::input::InputSource;
+use crate::runner::report::print_rows;
+use crate::runner::solution::{DynSolution, Part};
+use crate::runner::catch_panic;
+use crate::utility::generic_error::{GenericError, GenericResult};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Phase {
+    Parse,
+    Solve(Part),
+}
+
+#[derive(Debug)]
+pub struct BenchReport {
+    pub year: u16,
+    pub day: u16,
+    pub phase: Phase,
+    pub samples: Vec<Duration>,
+    // A phase that failed has no samples, only the error
+    pub error: Option<GenericError>,
+}
+
+impl BenchReport {
+    fn new(year: u16, day: u16, phase: Phase, mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        BenchReport { year, day, phase, samples, error: None }
+    }
+
+    pub fn failed(year: u16, day: u16, phase: Phase, error: GenericError) -> Self {
+        BenchReport { year, day, phase, samples: Vec::new(), error: Some(error) }
+    }
+
+    pub fn min(&self) -> Duration {
+        self.samples[0]
+    }
+
+    pub fn max(&self) -> Duration {
+        self.samples[self.samples.len() - 1]
+    }
+
+    pub fn median(&self) -> Duration {
+        let middle = self.samples.len() / 2;
+        if self.samples.len().is_multiple_of(2) {
+            (self.samples[middle - 1] + self.samples[middle]) / 2
+        } else {
+            self.samples[middle]
+        }
+    }
+
+    pub fn mean(&self) -> Duration {
+        self.samples.iter().sum::<Duration>() / self.samples.len() as u32
+    }
+}
+
+// A phase that fails is recorded and the rest of the day carries on where it can, only an input
+// that can't be read is returned as an error
+pub fn bench_solution(solution: &dyn DynSolution, runs: Vec<(InputSource, Vec<Part>)>, iterations: usize, context: &Context) -> GenericResult<Vec<BenchReport>> {
+    assert!(iterations > 0);
+    let metadata = solution.metadata();
+    let mut reports = Vec::new();
+
+    'runs: for (input_source, parts) in runs {
+        let input = input_source.read()?;
+
+        let mut parse_samples = Vec::with_capacity(iterations);
+        let mut parsed = None;
+        for _ in 0..iterations {
+            let parse_start = Instant::now();
+            match catch_panic(|| solution.parse(&input)) {
+                Ok(input) => parsed = Some(input),
+                Err(e) => {
+                    reports.push(BenchReport::failed(metadata.year, metadata.day, Phase::Parse, e.with_source(&input_source.to_string())));
+                    continue 'runs;
+                }
+            }
+            parse_samples.push(parse_start.elapsed());
+        }
+        reports.push(BenchReport::new(metadata.year, metadata.day, Phase::Parse, parse_samples));
+
+        let Some(parsed) = parsed else {
+            continue;
+        };
+        'parts: for part in parts {
+            let mut solve_samples = Vec::with_capacity(iterations);
+            for _ in 0..iterations {
+                let solve_start = Instant::now();
+                if let Err(e) = catch_panic(|| solution.solve(part, parsed.as_ref(), context)) {
+                    reports.push(BenchReport::failed(metadata.year, metadata.day, Phase::Solve(part), e));
+                    continue 'parts;
+                }
+                solve_samples.push(solve_start.elapsed());
+            }
+            reports.push(BenchReport::new(metadata.year, metadata.day, Phase::Solve(part), solve_samples));
+        }
+    }
+
+    Ok(reports)
+}
+
+pub fn print_table(reports: &[BenchReport]) {
+    let rows: Vec<[String; 9]> = reports.iter().map(|report| {
+        let phase = match report.phase {
+            Phase::Parse => "parse".to_string(),
+            Phase::Solve(part) => format!("part {}", part),
+        };
+        match &report.error {
+            Some(e) => [report.year.to_string(), report.day.to_string(), phase, "0".to_string(), String::new(), String::new(), String::new(), String::new(), format!("failed: {}", e)],
+            None => [
+                report.year.to_string(),
+                report.day.to_string(),
+                phase,
+                report.samples.len().to_string(),
+                format!("{:.3?}", report.min()),
+                format!("{:.3?}", report.median()),
+                format!("{:.3?}", report.mean()),
+                format!("{:.3?}", report.max()),
+                "ok".to_string(),
+            ],
+        }
+    }).collect();
+
+    print_rows(["Year", "Day", "Phase", "Runs", "Min", "Median", "Mean", "Max", "Status"], &rows);
+}
+
+#[test]
+pub fn run_test_statistics() {
+    let samples = [4, 1, 3, 2].map(Duration::from_millis).to_vec();
+    let report = BenchReport::new(2024, 1, Phase::Parse, samples);
+    assert_eq!(report.min(), Duration::from_millis(1));
+    assert_eq!(report.max(), Duration::from_millis(4));
+    assert_eq!(report.median(), Duration::from_micros(2500));
+    assert_eq!(report.mean(), Duration::from_micros(2500));
+}
+
+#[test]
+pub fn run_test_bench_failures() -> GenericResult<()> {
+    let solution = crate::implementations::registry().find(2024, 1)?;
+    let runs = vec![(InputSource::File(std::path::PathBuf::from("Cargo.toml")), Part::ALL.to_vec())];
+    let reports = bench_solution(solution, runs, 2, &Context::default())?;
+    assert_eq!(reports.len(), 1);
+    assert!(matches!(&reports[0], BenchReport { phase: Phase::Parse, error: Some(GenericError::Located { source: Some(source), .. }), .. } if source == "Cargo.toml"));
+    Ok(())
+}
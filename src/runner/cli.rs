@@ -0,0 +1,480 @@
+use clap::{CommandFactory, FromArgMatches, Parser, Subcommand};
+use clap::parser::ValueSource;
+
+use crate::implementations;
+use crate::runner;
+use crate::utility::generic_error::{GenericError, GenericResult};
+
+#[derive(clap::Args, Debug)]
+struct RemoteArgs {
+    /// Server to talk to, the token is sent as the session cookie
+    #[arg(long, default_value = runner::remote::DEFAULT_BASE_URL)]
+    base_url: String,
+
+    /// File holding the session token when AOC_SESSION is not set, defaults to the config's session_file or ~/.config/aoc/session
+    #[arg(long)]
+    session_file: Option<std::path::PathBuf>,
+}
+
+impl RemoteArgs {
+    fn connect(&self) -> GenericResult<runner::remote::Client> {
+        let session_path = self.session_file.clone().or_else(runner::remote::default_session_path);
+        let session = runner::remote::session_token(session_path.as_deref())?;
+        Ok(runner::remote::Client::new(&self.base_url, session))
+    }
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Generate the module, registration and data directory for a new day
+    NewDay {
+        day: u16,
+
+        #[arg(short, long, default_value_t = runner::DEFAULT_YEAR)]
+        year: u16,
+
+        /// Puzzle title stored in the day's metadata
+        #[arg(short, long, default_value = "Untitled")]
+        title: String,
+    },
+    /// Download the puzzle input to data/YEAR/dayN/input.txt unless it is already there
+    Fetch {
+        #[arg(short, long)]
+        day: u16,
+
+        #[arg(short, long, default_value_t = runner::DEFAULT_YEAR)]
+        year: u16,
+
+        #[command(flatten)]
+        remote: RemoteArgs,
+    },
+    /// Solve a part against the puzzle input and submit the answer, unless earlier submissions rule it out
+    Submit {
+        #[arg(short, long)]
+        day: u16,
+
+        #[arg(short, long, default_value_t = runner::DEFAULT_YEAR)]
+        year: u16,
+
+        #[arg(short, long)]
+        part: runner::solution::Part,
+
+        #[command(flatten)]
+        remote: RemoteArgs,
+    },
+}
+
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None, args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[arg(short, long, required_unless_present = "all")]
+    day: Option<u16>,
+
+    /// Which event to run days from, defaults to the config's year or 2024
+    #[arg(short, long, default_value_t = runner::DEFAULT_YEAR)]
+    year: u16,
+
+    /// Directory holding the YEAR/dayN data, overrides AOC_DATA_DIR and the config's data_dir
+    #[arg(long, global = true)]
+    data_dir: Option<std::path::PathBuf>,
+
+    /// Show more diagnostics on stderr, repeat for more detail
+    #[arg(short, long, global = true, action = clap::ArgAction::Count, conflicts_with = "quiet")]
+    verbose: u8,
+
+    /// Show fewer diagnostics on stderr, repeat to only show errors
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    quiet: u8,
+
+    /// Only run the given part, both parts are run by default
+    #[arg(short, long)]
+    part: Option<runner::solution::Part>,
+
+    /// Read the puzzle input from this path instead of data/YEAR/dayN/input.txt, use - for stdin
+    #[arg(short, long)]
+    input: Option<runner::input::InputSource>,
+
+    /// Run against the example input for each part instead of the puzzle input
+    #[arg(short, long, conflicts_with = "input")]
+    example: bool,
+
+    /// Run every registered day of the year and print a summary table
+    #[arg(short, long, conflicts_with_all = ["day", "input"])]
+    all: bool,
+
+    /// Run N times and report parse and solve timings instead of answers
+    #[arg(short, long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+    bench: Option<u32>,
+
+    /// Compare the answers against data/YEAR/dayN/answers.toml and fail on a mismatch
+    #[arg(long, conflicts_with_all = ["input", "example", "bench", "record"])]
+    verify: bool,
+
+    /// Store the answers in data/YEAR/dayN/answers.toml
+    #[arg(long, conflicts_with_all = ["input", "example", "bench"])]
+    record: bool,
+
+    /// Number of days to run at once when running more than one day
+    #[arg(short, long, value_name = "N", default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..), conflicts_with = "bench")]
+    jobs: u32,
+
+    /// Re-run whenever the input file changes
+    #[arg(short, long, conflicts_with_all = ["all", "bench", "verify", "record"])]
+    watch: bool,
+
+    /// Run this implementation of each part instead of the default one
+    #[arg(long, conflicts_with = "all")]
+    variant: Option<String>,
+
+    /// Run every implementation of each part and fail if their answers differ
+    #[arg(long, conflicts_with_all = ["variant", "bench", "verify", "record", "watch"])]
+    cross_check: bool,
+
+    /// Give up on a day that takes longer than this many seconds and record it as failed
+    #[arg(long, value_name = "SECONDS", value_parser = parse_seconds, conflicts_with_all = ["bench", "watch", "cross_check"])]
+    timeout: Option<std::time::Duration>,
+
+    /// Pass a tuning parameter to the solutions, can be given more than once
+    #[arg(long = "param", value_name = "KEY=VALUE", value_parser = runner::context::parse_param)]
+    params: Vec<(String, String)>,
+
+    /// How answers, timings and errors are printed
+    #[arg(short, long, value_enum, default_value_t)]
+    format: runner::output::Format,
+}
+
+// Days run on the worker threads, so the timings in each report are measured there
+fn run_solutions(args: &Args, solutions: &[&'static dyn runner::solution::DynSolution], parts: &[runner::solution::Part], example: bool) -> Vec<Vec<runner::report::PartReport>> {
+    runner::pool::map_in_parallel(solutions, args.jobs as usize, |solution| {
+        let metadata = solution.metadata();
+        let runs = runner::plan_runs(metadata.year, metadata.day, parts, example, None);
+        runner::run_solution_with_timeout(*solution, runs, &args.context(), args.timeout)
+    })
+}
+
+fn run_all(args: &Args, registry: &'static runner::registry::Registry, parts: &[runner::solution::Part]) -> GenericResult<()> {
+    let solutions = selected_solutions(args, registry)?;
+    let reports: Vec<runner::report::PartReport> = run_solutions(args, &solutions, parts, args.example).into_iter().flatten().collect();
+
+    runner::output::print_reports(args.format, &reports);
+
+    let failures = reports.iter().filter(|report| matches!(report.status, runner::report::Status::Failed(_))).count();
+    if failures > 0 {
+        return Err(GenericError::BasicError(format!("{} part(s) failed", failures)));
+    }
+    Ok(())
+}
+
+fn selected_solutions<'a>(args: &Args, registry: &'a runner::registry::Registry) -> GenericResult<Vec<&'a dyn runner::solution::DynSolution>> {
+    match args.day {
+        Some(day) if !args.all => Ok(vec![registry.find(args.year, day)?]),
+        _ => {
+            let solutions: Vec<_> = registry.iter_year(args.year).collect();
+            if solutions.is_empty() {
+                let years: Vec<String> = registry.years().iter().map(|year| year.to_string()).collect();
+                return Err(GenericError::IOError(std::io::Error::new(std::io::ErrorKind::InvalidInput,
+                    format!("No days registered for {} (available years: {})", args.year, years.join(", ")))));
+            }
+            Ok(solutions)
+        }
+    }
+}
+
+fn run_verify(args: &Args, registry: &'static runner::registry::Registry, parts: &[runner::solution::Part]) -> GenericResult<()> {
+    let solutions = selected_solutions(args, registry)?;
+    let mut verifications = Vec::new();
+    for (solution, reports) in solutions.iter().zip(run_solutions(args, &solutions, parts, false)) {
+        let runner::solution::Metadata { year, day, .. } = solution.metadata();
+        let answers = runner::verify::Answers::load(year, day)?;
+        verifications.extend(reports.into_iter().map(|report| runner::verify::Verification::new(report, &answers)));
+    }
+
+    runner::output::print_verification(args.format, &verifications);
+
+    if !verifications.iter().any(|verification| verification.was_compared()) {
+        crate::log_warn!("Nothing was verified, no part was both solved and recorded");
+    }
+
+    let explicit = args.day.is_some() && !args.all;
+    let failures = verifications.iter().filter(|verification| verification.is_failure(explicit)).count();
+    if failures > 0 {
+        return Err(GenericError::BasicError(format!("{} part(s) failed verification", failures)));
+    }
+    Ok(())
+}
+
+fn run_cross_check(args: &Args, registry: &runner::registry::Registry, parts: &[runner::solution::Part]) -> GenericResult<()> {
+    let solutions = selected_solutions(args, registry)?;
+    let reports: Vec<runner::cross_check::VariantReport> = runner::pool::map_in_parallel(&solutions, args.jobs as usize, |solution| {
+        let metadata = solution.metadata();
+        let runs = runner::plan_runs(metadata.year, metadata.day, parts, args.example, args.input.as_ref());
+        runner::cross_check::run_variants(*solution, runs, &args.context())
+    }).into_iter().flatten().collect();
+
+    runner::output::print_cross_check(args.format, &reports);
+
+    let problems = runner::cross_check::problems(&reports);
+    if !problems.is_empty() {
+        return Err(GenericError::BasicError(problems.join("\n")));
+    }
+    Ok(())
+}
+
+fn run_record(args: &Args, registry: &'static runner::registry::Registry, parts: &[runner::solution::Part]) -> GenericResult<()> {
+    let solutions = selected_solutions(args, registry)?;
+    let mut all_reports = Vec::new();
+    for (solution, reports) in solutions.iter().zip(run_solutions(args, &solutions, parts, false)) {
+        let runner::solution::Metadata { year, day, .. } = solution.metadata();
+
+        let mut answers = runner::verify::Answers::load(year, day)?;
+        let mut recorded = 0;
+        for report in reports.iter() {
+            if let runner::report::Status::Solved(answer) = &report.status {
+                answers.set(report.part, answer);
+                recorded += 1;
+            }
+        }
+
+        if recorded > 0 {
+            answers.save(year, day)?;
+            crate::log_info!("Recorded {} answer(s) to {}", recorded, runner::verify::answers_path(year, day).display());
+        }
+        all_reports.extend(reports);
+    }
+
+    runner::output::print_reports(args.format, &all_reports);
+
+    let failures = all_reports.iter().filter(|report| matches!(report.status, runner::report::Status::Failed(_))).count();
+    if failures > 0 {
+        return Err(GenericError::BasicError(format!("{} part(s) failed", failures)));
+    }
+    Ok(())
+}
+
+fn run_bench(args: &Args, registry: &runner::registry::Registry, parts: &[runner::solution::Part], iterations: u32) -> GenericResult<()> {
+    let solutions = selected_solutions(args, registry)?;
+
+    let mut reports = Vec::new();
+    for solution in solutions {
+        let runner::solution::Metadata { year, day, .. } = solution.metadata();
+        let runs = runner::plan_runs(year, day, parts, args.example, args.input.as_ref());
+        match runner::bench::bench_solution(solution, runs, iterations as usize, &args.context()) {
+            Ok(day_reports) => reports.extend(day_reports),
+            Err(GenericError::IOError(e)) if args.all && e.kind() == std::io::ErrorKind::NotFound => {
+                crate::log_warn!("Skipping day {} of {}: {}", day, year, e);
+            }
+            Err(e) => reports.push(runner::bench::BenchReport::failed(year, day, runner::bench::Phase::Parse, e)),
+        }
+    }
+
+    runner::output::print_bench(args.format, &reports);
+
+    let failures = reports.iter().filter(|report| report.error.is_some()).count();
+    // A single day's error is shown in full, with the input it came from
+    if failures == 1 && !args.all {
+        if let Some(error) = reports.into_iter().find_map(|report| report.error) {
+            return Err(error);
+        }
+    } else if failures > 0 {
+        return Err(GenericError::BasicError(format!("{} phase(s) failed", failures)));
+    }
+    Ok(())
+}
+
+fn run_watch(args: &Args, registry: &runner::registry::Registry, day: u16, parts: &[runner::solution::Part]) -> GenericResult<()> {
+    let solution = registry.find(args.year, day)?;
+    let runs = runner::plan_runs(args.year, day, parts, args.example, args.input.as_ref());
+
+    let mut paths = Vec::new();
+    for (input_source, _) in runs.iter() {
+        match input_source {
+            runner::input::InputSource::File(path) => paths.push(path.clone()),
+            runner::input::InputSource::Stdin => return Err(GenericError::BasicError("--watch needs an input file, it can't watch stdin".to_string())),
+        }
+    }
+
+    let mut watcher = runner::watch::Watcher::new(paths);
+    let metadata = solution.metadata();
+    let watched: Vec<String> = watcher.paths().iter().map(|path| path.display().to_string()).collect();
+    crate::log_info!("Watching {} for day {} of {}: {}", watched.join(", "), metadata.day, metadata.year, metadata.title);
+
+    loop {
+        let reports = runner::run_solution(solution, runs.clone(), &args.context());
+        if args.format == runner::output::Format::Text {
+            for report in reports.iter() {
+                match &report.status {
+                    runner::report::Status::Solved(answer) => println!("Part {} result:{}{} ({:.3?})", report.part.name(), answer_separator(answer), answer, report.elapsed),
+                    runner::report::Status::Skipped(e) | runner::report::Status::Failed(e) => println!("Part {} failed: {}", report.part.name(), e),
+                }
+            }
+        } else {
+            runner::output::print_reports(args.format, &reports);
+        }
+
+        watcher.wait_for_change();
+        crate::log_info!("Input changed, re-running");
+    }
+}
+
+// Multi-line answers start on their own line so ASCII art stays aligned
+fn answer_separator(answer: &runner::answer::Answer) -> &'static str {
+    if answer.is_multi_line() { "\n" } else { " " }
+}
+
+fn run_day(args: &Args, registry: &'static runner::registry::Registry, day: u16, parts: &[runner::solution::Part]) -> GenericResult<()> {
+    let solution = registry.find(args.year, day)?;
+
+    let runs = runner::plan_runs(args.year, day, parts, args.example, args.input.as_ref());
+    let reports = runner::run_solution_with_timeout(solution, runs, &args.context(), args.timeout);
+
+    if args.format != runner::output::Format::Text {
+        runner::output::print_reports(args.format, &reports);
+        return match reports.into_iter().find(|report| !matches!(report.status, runner::report::Status::Solved(_))) {
+            Some(runner::report::PartReport { status: runner::report::Status::Skipped(e) | runner::report::Status::Failed(e), .. }) => Err(e),
+            _ => Ok(()),
+        };
+    }
+
+    let metadata = solution.metadata();
+    println!("Day {} of {}: {}", metadata.day, metadata.year, metadata.title);
+
+    for report in reports {
+        match report.status {
+            runner::report::Status::Solved(answer) => println!("Part {} result:{}{}", report.part.name(), answer_separator(&answer), answer),
+            runner::report::Status::Skipped(e) | runner::report::Status::Failed(e) => return Err(e),
+        }
+    }
+    Ok(())
+}
+
+fn run_command(command: &Command) -> GenericResult<()> {
+    match command {
+        Command::NewDay { day, year, title } => {
+            let source_root = std::path::Path::new(env!("CARGO_MANIFEST_DIR"));
+            for path in runner::scaffold::new_day(source_root, *year, *day, title)? {
+                crate::log_info!("Wrote {}", path.display());
+            }
+            Ok(())
+        }
+        Command::Fetch { day, year, remote } => {
+            let path = runner::input::puzzle_input_path(*year, *day);
+            let fetched = runner::remote::fetch_input(&path, *year, *day, || remote.connect())?;
+            match fetched {
+                runner::remote::Fetched::Cached => crate::log_info!("Already cached at {}", path.display()),
+                runner::remote::Fetched::Downloaded => crate::log_info!("Wrote {}", path.display()),
+            }
+            Ok(())
+        }
+        Command::Submit { day, year, part, remote } => {
+            let solution = implementations::registry().find(*year, *day)?;
+            let runs = runner::plan_runs(*year, *day, &[*part], false, None);
+            let answer = match runner::run_solution(solution, runs, &runner::context::Context::default()).remove(0).status {
+                runner::report::Status::Solved(answer) => answer,
+                runner::report::Status::Skipped(e) | runner::report::Status::Failed(e) => return Err(e),
+            };
+            println!("Part {} result:{}{}", part.name(), answer_separator(&answer), answer);
+
+            let mut history = runner::submit::History::load(*year, *day)?;
+            let outcome = runner::submit::submit(&mut history, *year, *day, *part, &answer, || remote.connect())?;
+            history.save(*year, *day)?;
+            println!("Submitted: {}", outcome);
+            Ok(())
+        }
+    }
+}
+
+fn parse_seconds(seconds: &str) -> Result<std::time::Duration, String> {
+    let seconds: f64 = seconds.parse().map_err(|e| format!("{}", e))?;
+    std::time::Duration::try_from_secs_f64(seconds).ok().filter(|duration| !duration.is_zero())
+        .ok_or_else(|| "expected a positive number of seconds".to_string())
+}
+
+impl Args {
+    fn context(&self) -> runner::context::Context {
+        let mut context = runner::context::Context::new(self.params.clone());
+        if let Some(variant) = &self.variant {
+            context = context.with_variant(variant);
+        }
+        // Days running at the same time would draw over each other's bars
+        if self.jobs == 1 {
+            if let Some(progress) = runner::progress::ProgressBar::for_stderr() {
+                context = context.with_progress(progress);
+            }
+        }
+        context
+    }
+}
+
+fn set_from_config<T>(matches: &clap::ArgMatches, id: &str, value: &mut T, configured: Option<T>) {
+    if matches.value_source(id) == Some(ValueSource::CommandLine) {
+        return;
+    }
+    if let Some(configured) = configured {
+        *value = configured;
+    }
+}
+
+// Flags given on the command line win over aoc.toml, which wins over the built-in defaults
+fn apply_config(args: &mut Args, matches: &clap::ArgMatches, config: runner::config::Config) {
+    set_from_config(matches, "year", &mut args.year, config.year);
+    set_from_config(matches, "format", &mut args.format, config.format);
+
+    if let Some((_, command_matches)) = matches.subcommand() {
+        match &mut args.command {
+            Some(Command::NewDay { year, .. }) => set_from_config(command_matches, "year", year, config.year),
+            Some(Command::Fetch { year, remote, .. } | Command::Submit { year, remote, .. }) => {
+                set_from_config(command_matches, "year", year, config.year);
+                set_from_config(command_matches, "session_file", &mut remote.session_file, config.session_file.map(Some));
+            }
+            None => {},
+        }
+    }
+
+    if let Some(data_dir) = args.data_dir.clone().or(config.data_dir) {
+        runner::input::set_data_root(data_dir);
+    }
+}
+
+pub fn run() -> GenericResult<()> {
+    let matches = Args::command().get_matches();
+    let mut args = Args::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+    crate::utility::log::set_max_level(crate::utility::log::Level::from_verbosity(args.verbose, args.quiet));
+    apply_config(&mut args, &matches, runner::config::Config::discover()?);
+
+    if let Some(command) = &args.command {
+        return run_command(command);
+    }
+
+    let registry = implementations::registry();
+    runner::context::check_params(&args.params, &selected_solutions(&args, registry)?)?;
+
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => runner::solution::Part::ALL.to_vec(),
+    };
+
+    if let Some(iterations) = args.bench {
+        return run_bench(&args, registry, &parts, iterations);
+    }
+
+    if args.verify {
+        return run_verify(&args, registry, &parts);
+    }
+
+    if args.record {
+        return run_record(&args, registry, &parts);
+    }
+
+    if args.cross_check {
+        return run_cross_check(&args, registry, &parts);
+    }
+
+    match args.day {
+        Some(day) if args.watch => run_watch(&args, registry, day, &parts),
+        Some(day) if !args.all => run_day(&args, registry, day, &parts),
+        _ => run_all(&args, registry, &parts),
+    }
+}
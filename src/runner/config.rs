@@ -0,0 +1,66 @@
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::runner::output::Format;
+use crate::utility::generic_error::GenericResult;
+
+pub const CONFIG_FILE_NAME: &str = "aoc.toml";
+pub const DATA_DIR_ENV: &str = "AOC_DATA_DIR";
+
+// Every setting is optional, whatever is missing falls back to the built-in default
+#[derive(Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub data_dir: Option<PathBuf>,
+    pub year: Option<u16>,
+    pub format: Option<Format>,
+    pub session_file: Option<PathBuf>,
+}
+
+impl Config {
+    // Relative paths in the file are relative to the directory the file is in
+    pub fn load(path: &Path) -> GenericResult<Config> {
+        let mut config: Config = toml::from_str(&std::fs::read_to_string(path)?)?;
+        let base = path.parent().unwrap_or(Path::new(""));
+        config.data_dir = config.data_dir.map(|data_dir| base.join(data_dir));
+        config.session_file = config.session_file.map(|session_file| base.join(session_file));
+        Ok(config)
+    }
+
+    // The nearest aoc.toml from the working directory up, with AOC_DATA_DIR taking precedence over its data_dir
+    pub fn discover() -> GenericResult<Config> {
+        let mut config = match find_config_file(&std::env::current_dir()?) {
+            Some(path) => Config::load(&path)?,
+            None => Config::default(),
+        };
+        if let Some(data_dir) = std::env::var_os(DATA_DIR_ENV).filter(|data_dir| !data_dir.is_empty()) {
+            config.data_dir = Some(PathBuf::from(data_dir));
+        }
+        Ok(config)
+    }
+}
+
+pub fn find_config_file(start: &Path) -> Option<PathBuf> {
+    start.ancestors().map(|directory| directory.join(CONFIG_FILE_NAME)).find(|path| path.is_file())
+}
+
+#[test]
+pub fn run_test_config() -> GenericResult<()> {
+    let root = std::env::temp_dir().join(format!("aoc_config_test_{}", std::process::id()));
+    let nested = root.join("src").join("implementations");
+    std::fs::create_dir_all(&nested)?;
+    std::fs::write(root.join(CONFIG_FILE_NAME), "data_dir = \"inputs\"\nyear = 2023\nformat = \"json\"\n")?;
+
+    let path = find_config_file(&nested);
+    assert_eq!(path, Some(root.join(CONFIG_FILE_NAME)));
+
+    let config = Config::load(&root.join(CONFIG_FILE_NAME))?;
+    assert_eq!(config, Config { data_dir: Some(root.join("inputs")), year: Some(2023), format: Some(Format::Json), session_file: None });
+
+    std::fs::write(root.join(CONFIG_FILE_NAME), "output = \"json\"\n")?;
+    assert!(Config::load(&root.join(CONFIG_FILE_NAME)).is_err());
+
+    std::fs::remove_dir_all(&root)?;
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::runner::progress::{DetachableProgress, Progress};
+use crate::runner::solution::DynSolution;
+use crate::utility::generic_error::{GenericError, GenericResult};
+
+// Everything a part gets from the runner besides its parsed input
+#[derive(Clone, Debug, Default)]
+pub struct Context {
+    params: BTreeMap<String, String>,
+    variant: Option<String>,
+    progress: Option<Arc<dyn Progress>>,
+}
+
+impl Context {
+    pub fn new(params: impl IntoIterator<Item = (String, String)>) -> Self {
+        Context { params: params.into_iter().collect(), variant: None, progress: None }
+    }
+
+    pub fn with_progress(mut self, progress: Arc<dyn Progress>) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
+    // Routes progress through a handle the runner can cut off, for a run it might stop waiting for
+    pub fn with_detachable_progress(mut self) -> (Self, Option<Arc<DetachableProgress>>) {
+        let detachable = self.progress.take().map(DetachableProgress::new);
+        self.progress = detachable.clone().map(|progress| progress as Arc<dyn Progress>);
+        (self, detachable)
+    }
+
+    // Long running parts call this as they go, it does nothing unless the runner is showing progress
+    pub fn progress(&self, done: usize, total: usize) {
+        if let Some(progress) = &self.progress {
+            progress.update(done, total);
+        }
+    }
+
+    pub fn start_progress(&self, label: &str) {
+        if let Some(progress) = &self.progress {
+            progress.start(label);
+        }
+    }
+
+    pub fn finish_progress(&self) {
+        if let Some(progress) = &self.progress {
+            progress.finish();
+        }
+    }
+
+    pub fn with_variant(mut self, variant: &str) -> Self {
+        self.variant = Some(variant.to_string());
+        self
+    }
+
+    // Which implementation of a part to run, None runs the one behind part_1 and part_2
+    pub fn variant(&self) -> Option<&str> {
+        self.variant.as_deref()
+    }
+
+    pub fn with_param(mut self, key: &str, value: &str) -> Self {
+        self.params.insert(key.to_string(), value.to_string());
+        self
+    }
+
+    // Parameters are stored as given on the command line and parsed into whatever type the day asks for
+    pub fn param<T: FromStr>(&self, key: &str, default: T) -> GenericResult<T> where T::Err: Display {
+        match self.params.get(key) {
+            Some(value) => value.parse().map_err(|e| GenericError::BasicError(format!("Invalid value '{}' for parameter {}: {}", value, key, e))),
+            None => Ok(default),
+        }
+    }
+}
+
+// A misspelt key would otherwise run with the default and look like it worked
+pub fn check_params(params: &[(String, String)], solutions: &[&dyn DynSolution]) -> GenericResult<()> {
+    for (key, _) in params {
+        if solutions.iter().any(|solution| solution.params().contains(&key.as_str())) {
+            continue;
+        }
+
+        let mut accepted: Vec<&str> = solutions.iter().flat_map(|solution| solution.params().iter().copied()).collect();
+        accepted.sort();
+        accepted.dedup();
+        let accepted = if accepted.is_empty() { "none".to_string() } else { accepted.join(", ") };
+        return Err(GenericError::BasicError(format!("Unknown parameter '{}' (accepted parameters: {})", key, accepted)));
+    }
+    Ok(())
+}
+
+pub fn parse_param(param: &str) -> Result<(String, String), String> {
+    match param.split_once('=') {
+        Some((key, value)) if !key.trim().is_empty() => Ok((key.trim().to_string(), value.to_string())),
+        _ => Err(format!("expected KEY=VALUE, got '{}'", param)),
+    }
+}
+
+#[test]
+pub fn run_test_params() -> GenericResult<()> {
+    assert_eq!(parse_param("word=SAMX"), Ok(("word".to_string(), "SAMX".to_string())));
+    assert_eq!(parse_param("empty="), Ok(("empty".to_string(), String::new())));
+    assert!(parse_param("dampening").is_err());
+    assert!(parse_param("=true").is_err());
+
+    let context = Context::new([parse_param("dampening=false").map_err(GenericError::BasicError)?]).with_param("limit", "12");
+    assert!(!context.param("dampening", true)?);
+    assert_eq!(context.param("limit", 0u32)?, 12);
+    assert_eq!(context.param("word", "XMAS".to_string())?, "XMAS");
+    assert!(context.param("limit", false).is_err());
+    Ok(())
+}
+
+#[test]
+pub fn run_test_check_params() -> GenericResult<()> {
+    let registry = crate::implementations::registry();
+    let day2 = registry.find(2024, 2)?;
+    let day4 = registry.find(2024, 4)?;
+    let param = |key: &str| vec![(key.to_string(), "false".to_string())];
+
+    assert!(check_params(&param("dampening"), &[day2]).is_ok());
+    assert!(check_params(&param("dampening"), &[day2, day4]).is_ok());
+    let error = check_params(&param("dampning"), &[day2, day4]).unwrap_err();
+    assert_eq!(error.to_string(), "basic error: Unknown parameter 'dampning' (accepted parameters: dampening, word)");
+    assert!(check_params(&param("word"), &[registry.find(2024, 1)?]).is_err());
+    Ok(())
+}
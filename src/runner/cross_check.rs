@@ -0,0 +1,148 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use crate::runner::answer::Answer;
+use crate::runner::context::Context;
+use crate::runner::input::InputSource;
+use crate::runner::report::{print_rows, PartReport, Status};
+use crate::runner::solution::{DynSolution, Part};
+use crate::runner::{load_input, solve_part};
+use crate::utility::generic_error::GenericError;
+
+#[derive(Debug)]
+pub struct VariantReport {
+    pub variant: &'static str,
+    pub report: PartReport,
+}
+
+// Runs every variant of each part against the same input, which is only read and parsed once
+pub fn run_variants(solution: &dyn DynSolution, runs: Vec<(InputSource, Vec<Part>)>, context: &Context) -> Vec<VariantReport> {
+    let mut result = Vec::new();
+    for (input_source, parts) in runs {
+        let mut failures = Vec::new();
+        match load_input(solution, &input_source, &parts, &mut failures) {
+            Some(parsed) => result.extend(solve_variants(solution, &parts, parsed.as_ref(), context)),
+            None => result.extend(failures.into_iter().flat_map(|report| share_failure(solution, report))),
+        }
+    }
+    result
+}
+
+fn solve_variants(solution: &dyn DynSolution, parts: &[Part], parsed: &dyn Any, context: &Context) -> Vec<VariantReport> {
+    let mut result = Vec::new();
+    for &part in parts {
+        for &variant in solution.variants(part) {
+            result.push(VariantReport { variant, report: solve_part(solution, part, parsed, &context.clone().with_variant(variant)) });
+        }
+    }
+    result
+}
+
+// An input that couldn't be loaded fails every variant of the part the same way
+fn share_failure(solution: &dyn DynSolution, report: PartReport) -> Vec<VariantReport> {
+    let PartReport { year, day, part, status, elapsed } = report;
+    let (status, error): (fn(GenericError) -> Status, GenericError) = match status {
+        Status::Skipped(error) => (Status::Skipped, error),
+        Status::Failed(error) => (Status::Failed, error),
+        Status::Solved(_) => return vec![VariantReport { variant: solution.variants(part)[0], report: PartReport { year, day, part, status, elapsed } }],
+    };
+
+    let error = Arc::new(error);
+    solution.variants(part).iter().map(|&variant| VariantReport {
+        variant,
+        report: PartReport { year, day, part, status: status(GenericError::SharedError(error.clone())), elapsed },
+    }).collect()
+}
+
+// One message per part that failed in some variant, and one per part whose variants solved it with
+// different answers
+pub fn problems(reports: &[VariantReport]) -> Vec<String> {
+    let mut result = Vec::new();
+    let mut remaining = reports;
+    while let Some(first) = remaining.first() {
+        let same_part = remaining.iter().take_while(|other| (other.report.year, other.report.day, other.report.part) == (first.report.year, first.report.day, first.report.part)).count();
+        let (group, rest) = remaining.split_at(same_part);
+        remaining = rest;
+        let PartReport { year, day, part, .. } = first.report;
+
+        let failed: Vec<(&str, &GenericError)> = group.iter().filter_map(|variant| match &variant.report.status {
+            Status::Failed(error) => Some((variant.variant, error)),
+            _ => None,
+        }).collect();
+        if let Some((_, error)) = failed.first() {
+            let variants: Vec<&str> = failed.iter().map(|(variant, _)| *variant).collect();
+            result.push(format!("Part {} of day {} of {} failed ({}): {}", part.name(), day, year, variants.join(", "), error));
+        }
+
+        let solved: Vec<(&str, &Answer)> = group.iter().filter_map(|variant| match &variant.report.status {
+            Status::Solved(answer) => Some((variant.variant, answer)),
+            _ => None,
+        }).collect();
+        if solved.iter().any(|(_, answer)| *answer != solved[0].1) {
+            let answers: Vec<String> = solved.iter().map(|(variant, answer)| format!("{}={}", variant, answer)).collect();
+            result.push(format!("Variants disagree on part {} of day {} of {}: {}", part.name(), day, year, answers.join(", ")));
+        }
+    }
+    result
+}
+
+pub fn print_table(reports: &[VariantReport]) {
+    let rows: Vec<[String; 7]> = reports.iter().map(|variant| [
+        variant.report.year.to_string(),
+        variant.report.day.to_string(),
+        variant.report.part.to_string(),
+        variant.variant.to_string(),
+        variant.report.answer(),
+        format!("{:.3?}", variant.report.elapsed),
+        variant.report.status(),
+    ]).collect();
+
+    print_rows(["Year", "Day", "Part", "Variant", "Answer", "Time", "Status"], &rows);
+}
+
+#[test]
+pub fn run_test_problems() {
+    use std::time::Duration;
+
+    let variant = |part, variant, status| VariantReport { variant, report: PartReport { year: 2024, day: 2, part, status, elapsed: Duration::ZERO } };
+    let reports = [
+        variant(Part::One, "fast", Status::Solved(Answer::Unsigned(2))),
+        variant(Part::One, "naive", Status::Solved(Answer::Unsigned(2))),
+        variant(Part::Two, "fast", Status::Solved(Answer::Unsigned(4))),
+        variant(Part::Two, "naive", Status::Solved(Answer::Unsigned(5))),
+    ];
+    assert_eq!(problems(&reports), vec!["Variants disagree on part two of day 2 of 2024: fast=4, naive=5".to_string()]);
+
+    let reports = [
+        variant(Part::One, "fast", Status::Solved(Answer::Unsigned(2))),
+        variant(Part::One, "naive", Status::Failed(GenericError::BasicError("bad".to_string()))),
+        variant(Part::Two, "fast", Status::Skipped(GenericError::BasicError("missing".to_string()))),
+        variant(Part::Two, "naive", Status::Skipped(GenericError::BasicError("missing".to_string()))),
+    ];
+    assert_eq!(problems(&reports), vec!["Part one of day 2 of 2024 failed (naive): basic error: bad".to_string()]);
+
+    let reports = [variant(Part::One, "default", Status::Failed(GenericError::BasicError("bad".to_string())))];
+    assert_eq!(problems(&reports), vec!["Part one of day 2 of 2024 failed (default): basic error: bad".to_string()]);
+}
+
+#[test]
+pub fn run_test_run_variants() -> crate::utility::generic_error::GenericResult<()> {
+    let solution = crate::implementations::registry().find(2024, 2)?;
+
+    // Each variant gets the same parsed input, as stdin can't be read twice
+    let parsed = solution.parse("7 6 4 2 1\n1 2 7 8 9\n1 3 2 4 5\n")?;
+    let reports = solve_variants(solution, &Part::ALL, parsed.as_ref(), &Context::default());
+    let answers: Vec<(&str, Option<Answer>)> = reports.iter().map(|variant| (variant.variant, match &variant.report.status {
+        Status::Solved(answer) => Some(answer.clone()),
+        _ => None,
+    })).collect();
+    assert_eq!(answers, vec![("fast", Some(Answer::Unsigned(1))), ("naive", Some(Answer::Unsigned(1))), ("fast", Some(Answer::Unsigned(2))), ("naive", Some(Answer::Unsigned(2)))]);
+    assert!(problems(&reports).is_empty());
+
+    let missing = InputSource::File(std::path::PathBuf::from("data/2024/day2/missing.txt"));
+    let reports = run_variants(solution, vec![(missing, Part::ALL.to_vec())], &Context::default());
+    assert_eq!(reports.len(), 4);
+    assert!(reports.iter().all(|variant| matches!(variant.report.status, Status::Skipped(_))));
+    assert!(problems(&reports).is_empty());
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use std::convert::Infallible;
+use std::fmt::{Display, Formatter, Error as FmtError};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use crate::runner::solution::Part;
+use crate::utility::generic_error::GenericResult;
+
+pub const DEFAULT_DATA_ROOT: &str = "data";
+
+static DATA_ROOT: OnceLock<PathBuf> = OnceLock::new();
+
+// Set once at startup from the config, until then paths are relative to the working directory
+pub fn set_data_root(root: PathBuf) {
+    DATA_ROOT.set(root).expect("The data root can only be set once");
+}
+
+pub fn data_root() -> &'static Path {
+    DATA_ROOT.get().map(PathBuf::as_path).unwrap_or(Path::new(DEFAULT_DATA_ROOT))
+}
+
+pub fn day_directory(year: u16, day: u16) -> PathBuf {
+    data_root().join(year.to_string()).join(format!("day{}", day))
+}
+
+pub fn puzzle_input_path(year: u16, day: u16) -> PathBuf {
+    day_directory(year, day).join("input.txt")
+}
+
+// Some days have a separate example per part, the rest share example.txt
+pub fn example_input_path(year: u16, day: u16, part: Part) -> PathBuf {
+    let part_example = day_directory(year, day).join(format!("example_part{}.txt", part));
+    if part_example.exists() {
+        return part_example;
+    }
+
+    day_directory(year, day).join("example.txt")
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    pub fn read(&self) -> GenericResult<String> {
+        match self {
+            InputSource::File(path) => std::fs::read_to_string(path)
+                .map_err(|e| std::io::Error::new(e.kind(), format!("{}: {}", path.display(), e)).into()),
+            InputSource::Stdin => {
+                let mut content = String::new();
+                std::io::stdin().read_to_string(&mut content)?;
+                Ok(content)
+            }
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), FmtError> {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
+
+impl FromStr for InputSource {
+    type Err = Infallible;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        if input == "-" {
+            Ok(InputSource::Stdin)
+        } else {
+            Ok(InputSource::File(PathBuf::from(input)))
+        }
+    }
+}
+
+#[test]
+pub fn run_test_from_str() {
+    assert_eq!("-".parse(), Ok(InputSource::Stdin));
+    assert_eq!("data/2024/day1/input.txt".parse(), Ok(InputSource::File(PathBuf::from("data/2024/day1/input.txt"))));
+}
+
+#[test]
+pub fn run_test_example_input_path() {
+    assert_eq!(example_input_path(2024, 3, Part::One), PathBuf::from("data/2024/day3/example_part1.txt"));
+    assert_eq!(example_input_path(2024, 3, Part::Two), PathBuf::from("data/2024/day3/example_part2.txt"));
+    assert_eq!(example_input_path(2024, 1, Part::Two), PathBuf::from("data/2024/day1/example.txt"));
+}
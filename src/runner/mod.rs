@@ -0,0 +1,234 @@
+use std::any::Any;
+use std::cell::Cell;
+use std::panic::AssertUnwindSafe;
+use std::sync::{Arc, Once};
+use std::time::{Duration, Instant};
+
+use crate::runner::context::Context;
+use crate::runner::input::InputSource;
+use crate::runner::report::{PartReport, Status};
+use crate::runner::solution::{DynSolution, Metadata, Part};
+use crate::utility::generic_error::{GenericError, GenericResult};
+
+pub mod answer;
+pub mod bench;
+pub mod cli;
+pub mod config;
+pub mod context;
+pub mod cross_check;
+pub mod input;
+pub mod output;
+pub mod pool;
+pub mod progress;
+pub mod registry;
+pub mod remote;
+pub mod report;
+pub mod scaffold;
+pub mod solution;
+pub mod submit;
+pub mod verify;
+pub mod watch;
+
+pub const DEFAULT_YEAR: u16 = 2024;
+
+// Parts that share an input only read and parse it once
+pub fn plan_runs(year: u16, day: u16, parts: &[Part], example: bool, input: Option<&InputSource>) -> Vec<(InputSource, Vec<Part>)> {
+    let mut runs: Vec<(InputSource, Vec<Part>)> = Vec::new();
+    for part in parts {
+        let input_source = if example {
+            InputSource::File(input::example_input_path(year, day, *part))
+        } else {
+            input.cloned().unwrap_or_else(|| InputSource::File(input::puzzle_input_path(year, day)))
+        };
+
+        match runs.iter_mut().find(|run| run.0 == input_source) {
+            Some(run) => run.1.push(*part),
+            None => runs.push((input_source, vec![*part])),
+        }
+    }
+    runs
+}
+
+// A failure before solving applies to every part sharing the input
+fn report_for_parts(reports: &mut Vec<PartReport>, year: u16, day: u16, parts: &[Part], error: GenericError, elapsed: Duration, status: fn(GenericError) -> Status) {
+    if let [part] = parts {
+        reports.push(PartReport { year, day, part: *part, status: status(error), elapsed });
+        return;
+    }
+
+    let error = Arc::new(error);
+    for part in parts {
+        reports.push(PartReport { year, day, part: *part, status: status(GenericError::SharedError(error.clone())), elapsed });
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&'static str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "unknown panic payload".to_string(),
+        },
+    }
+}
+
+thread_local! {
+    // Set while this thread runs a solver under catch_panic
+    static CATCHING_PANICS: Cell<bool> = const { Cell::new(false) };
+}
+
+// A caught panic ends up as a row in the summary, so the hook only mentions it at debug level.
+// Any other panic still goes to the hook that was there before.
+fn install_panic_hook() {
+    static INSTALLED: Once = Once::new();
+    INSTALLED.call_once(|| {
+        let previous_hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            if CATCHING_PANICS.get() {
+                crate::log_debug!("{}", info);
+            } else {
+                previous_hook(info);
+            }
+        }));
+    });
+}
+
+// Turns a panic into an error so one bad solver doesn't take the whole run down with it
+pub(crate) fn catch_panic<T>(f: impl FnOnce() -> GenericResult<T>) -> GenericResult<T> {
+    install_panic_hook();
+    let was_catching = CATCHING_PANICS.replace(true);
+    let result = std::panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING_PANICS.set(was_catching);
+    result.unwrap_or_else(|payload| Err(GenericError::Panic(panic_message(payload))))
+}
+
+// Reads and parses the input shared by parts, on failure every one of them is reported instead.
+// Stdin can only be read once, so anything solving a run more than once has to go through here.
+pub(crate) fn load_input(solution: &dyn DynSolution, input_source: &InputSource, parts: &[Part], reports: &mut Vec<PartReport>) -> Option<Box<dyn Any>> {
+    let Metadata { year, day, .. } = solution.metadata();
+    let input = match input_source.read() {
+        Ok(input) => input,
+        Err(GenericError::IOError(e)) if e.kind() == std::io::ErrorKind::NotFound => {
+            report_for_parts(reports, year, day, parts, e.into(), Duration::ZERO, Status::Skipped);
+            return None;
+        }
+        Err(e) => {
+            report_for_parts(reports, year, day, parts, e, Duration::ZERO, Status::Failed);
+            return None;
+        }
+    };
+
+    let parse_start = Instant::now();
+    match catch_panic(|| solution.parse(&input)) {
+        Ok(parsed) => Some(parsed),
+        Err(e) => {
+            report_for_parts(reports, year, day, parts, e.with_source(&input_source.to_string()), parse_start.elapsed(), Status::Failed);
+            None
+        }
+    }
+}
+
+pub(crate) fn solve_part(solution: &dyn DynSolution, part: Part, parsed: &dyn Any, context: &Context) -> PartReport {
+    let Metadata { year, day, .. } = solution.metadata();
+    context.start_progress(&format!("Day {} part {}", day, part.name()));
+    let solve_start = Instant::now();
+    let status = match catch_panic(|| solution.solve(part, parsed, context)) {
+        Ok(answer) => Status::Solved(answer),
+        Err(e) => Status::Failed(e),
+    };
+    context.finish_progress();
+    PartReport { year, day, part, status, elapsed: solve_start.elapsed() }
+}
+
+pub fn run_solution(solution: &dyn DynSolution, runs: Vec<(InputSource, Vec<Part>)>, context: &Context) -> Vec<PartReport> {
+    let mut reports = Vec::new();
+
+    for (input_source, parts) in runs {
+        let Some(parsed) = load_input(solution, &input_source, &parts, &mut reports) else {
+            continue;
+        };
+
+        for part in parts {
+            reports.push(solve_part(solution, part, parsed.as_ref(), context));
+        }
+    }
+
+    reports
+}
+
+// A thread can't be stopped from outside, so a day that runs out of time is left running in the
+// background and reported as failed. That is why the solution has to be 'static.
+pub fn run_solution_with_timeout(solution: &'static dyn DynSolution, runs: Vec<(InputSource, Vec<Part>)>, context: &Context, timeout: Option<Duration>) -> Vec<PartReport> {
+    let Some(timeout) = timeout else {
+        return run_solution(solution, runs, context);
+    };
+
+    let Metadata { year, day, .. } = solution.metadata();
+    let parts: Vec<Part> = runs.iter().flat_map(|(_, parts)| parts.iter().copied()).collect();
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let (context, progress) = context.clone().with_detachable_progress();
+    std::thread::spawn(move || {
+        // The receiver is gone if the day timed out, nobody is waiting for the reports then
+        let _ = sender.send(run_solution(solution, runs, &context));
+    });
+
+    match receiver.recv_timeout(timeout) {
+        Ok(reports) => reports,
+        Err(_) => {
+            if let Some(progress) = progress {
+                progress.detach();
+            }
+            parts.into_iter().map(|part| PartReport { year, day, part, status: Status::Failed(GenericError::Timeout(timeout)), elapsed: timeout }).collect()
+        }
+    }
+}
+
+#[test]
+pub fn run_test_plan_runs() {
+    let runs = plan_runs(2024, 1, &Part::ALL, false, None);
+    assert_eq!(runs, vec![(InputSource::File(input::puzzle_input_path(2024, 1)), Part::ALL.to_vec())]);
+
+    let runs = plan_runs(2024, 3, &Part::ALL, true, None);
+    assert_eq!(runs.len(), 2);
+
+    let runs = plan_runs(2024, 1, &[Part::Two], false, Some(&InputSource::Stdin));
+    assert_eq!(runs, vec![(InputSource::Stdin, vec![Part::Two])]);
+}
+
+#[cfg(test)]
+struct Misbehaving;
+
+#[cfg(test)]
+impl solution::Solution for Misbehaving {
+    type Input = ();
+
+    fn metadata(&self) -> Metadata {
+        Metadata { year: 2024, day: 99, title: "Misbehaving" }
+    }
+
+    fn parse(&self, _input: &str) -> GenericResult<Self::Input> {
+        Ok(())
+    }
+
+    fn part_1(&self, _input: &Self::Input, _context: &Context) -> GenericResult<answer::Answer> {
+        panic!("bad input");
+    }
+
+    fn part_2(&self, _input: &Self::Input, _context: &Context) -> GenericResult<answer::Answer> {
+        std::thread::sleep(Duration::from_secs(5));
+        Ok(answer::Answer::Unsigned(0))
+    }
+}
+
+#[test]
+pub fn run_test_panic_and_timeout() {
+    let runs = vec![(InputSource::File(std::path::PathBuf::from("Cargo.toml")), vec![Part::One])];
+    let reports = run_solution(&Misbehaving, runs, &Context::default());
+    assert!(matches!(&reports[0].status, Status::Failed(GenericError::Panic(message)) if message == "bad input"));
+    assert!(!CATCHING_PANICS.get());
+
+    let runs = vec![(InputSource::File(std::path::PathBuf::from("Cargo.toml")), Part::ALL.to_vec())];
+    let reports = run_solution_with_timeout(&Misbehaving, runs, &Context::default(), Some(Duration::from_millis(100)));
+    assert_eq!(reports.len(), 2);
+    assert!(reports.iter().all(|report| matches!(report.status, Status::Failed(GenericError::Timeout(_)))));
+}
@@ -0,0 +1,276 @@
+use std::time::Duration;
+
+use crate::runner::bench::{self, BenchReport, Phase};
+use crate::runner::cross_check::{self, VariantReport};
+use crate::runner::report::{self, PartReport, Status};
+use crate::runner::verify::{Verdict, Verification};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+fn json_string(value: &str) -> String {
+    let mut result = String::with_capacity(value.len() + 2);
+    result.push('"');
+    for character in value.chars() {
+        match character {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if (c as u32) < 0x20 => result.push_str(&format!("\\u{:04x}", c as u32)),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn status_name(status: &Status) -> &'static str {
+    match status {
+        Status::Solved(_) => "ok",
+        Status::Skipped(_) => "skipped",
+        Status::Failed(_) => "failed",
+    }
+}
+
+fn status_error(status: &Status) -> Option<String> {
+    match status {
+        Status::Solved(_) => None,
+        Status::Skipped(e) | Status::Failed(e) => Some(e.to_string()),
+    }
+}
+
+fn phase_name(phase: Phase) -> String {
+    match phase {
+        Phase::Parse => "parse".to_string(),
+        Phase::Solve(part) => format!("part{}", part),
+    }
+}
+
+fn verdict_name(verification: &Verification) -> &'static str {
+    match verification.verdict {
+        Verdict::Match => "match",
+        Verdict::Mismatch => "mismatch",
+        Verdict::NoStoredAnswer => "no_stored_answer",
+        Verdict::NotSolved => status_name(&verification.report.status),
+    }
+}
+
+fn nanoseconds(duration: Duration) -> u128 {
+    duration.as_nanos()
+}
+
+pub fn reports_to_json(reports: &[PartReport]) -> String {
+    let entries: Vec<String> = reports.iter().map(|report| {
+        let answer = match &report.status {
+            Status::Solved(_) => json_string(&report.answer()),
+            _ => "null".to_string(),
+        };
+        let error = status_error(&report.status).map(|e| json_string(&e)).unwrap_or_else(|| "null".to_string());
+        format!("{{\"year\":{},\"day\":{},\"part\":{},\"status\":\"{}\",\"answer\":{},\"elapsed_ns\":{},\"error\":{}}}",
+            report.year, report.day, report.part, status_name(&report.status), answer, nanoseconds(report.elapsed), error)
+    }).collect();
+
+    format!("{{\"results\":[{}]}}", entries.join(","))
+}
+
+pub fn reports_to_csv(reports: &[PartReport]) -> String {
+    let mut result = String::from("year,day,part,status,answer,elapsed_ns,error\n");
+    for report in reports {
+        result.push_str(&format!("{},{},{},{},{},{},{}\n",
+            report.year, report.day, report.part, status_name(&report.status), csv_field(&report.answer()),
+            nanoseconds(report.elapsed), csv_field(&status_error(&report.status).unwrap_or_default())));
+    }
+    result
+}
+
+pub fn bench_to_json(reports: &[BenchReport]) -> String {
+    let entries: Vec<String> = reports.iter().map(|report| match &report.error {
+        Some(e) => format!("{{\"year\":{},\"day\":{},\"phase\":\"{}\",\"runs\":0,\"min_ns\":null,\"median_ns\":null,\"mean_ns\":null,\"max_ns\":null,\"error\":{}}}",
+            report.year, report.day, phase_name(report.phase), json_string(&e.to_string())),
+        None => format!("{{\"year\":{},\"day\":{},\"phase\":\"{}\",\"runs\":{},\"min_ns\":{},\"median_ns\":{},\"mean_ns\":{},\"max_ns\":{},\"error\":null}}",
+            report.year, report.day, phase_name(report.phase), report.samples.len(), nanoseconds(report.min()),
+            nanoseconds(report.median()), nanoseconds(report.mean()), nanoseconds(report.max())),
+    }).collect();
+
+    format!("{{\"benchmarks\":[{}]}}", entries.join(","))
+}
+
+pub fn bench_to_csv(reports: &[BenchReport]) -> String {
+    let mut result = String::from("year,day,phase,runs,min_ns,median_ns,mean_ns,max_ns,error\n");
+    for report in reports {
+        match &report.error {
+            Some(e) => result.push_str(&format!("{},{},{},0,,,,,{}\n", report.year, report.day, phase_name(report.phase), csv_field(&e.to_string()))),
+            None => result.push_str(&format!("{},{},{},{},{},{},{},{},\n",
+                report.year, report.day, phase_name(report.phase), report.samples.len(), nanoseconds(report.min()),
+                nanoseconds(report.median()), nanoseconds(report.mean()), nanoseconds(report.max()))),
+        }
+    }
+    result
+}
+
+pub fn verification_to_json(verifications: &[Verification]) -> String {
+    let optional_json_string = |value: Option<String>| value.map(|v| json_string(&v)).unwrap_or_else(|| "null".to_string());
+    let entries: Vec<String> = verifications.iter().map(|verification| {
+        let report = &verification.report;
+        let answer = match &report.status {
+            Status::Solved(_) => Some(report.answer()),
+            _ => None,
+        };
+        format!("{{\"year\":{},\"day\":{},\"part\":{},\"result\":\"{}\",\"answer\":{},\"expected\":{},\"error\":{}}}",
+            report.year, report.day, report.part, verdict_name(verification), optional_json_string(answer),
+            optional_json_string(verification.expected.clone()), optional_json_string(status_error(&report.status)))
+    }).collect();
+
+    format!("{{\"verification\":[{}]}}", entries.join(","))
+}
+
+pub fn verification_to_csv(verifications: &[Verification]) -> String {
+    let mut result = String::from("year,day,part,result,answer,expected,error\n");
+    for verification in verifications {
+        let report = &verification.report;
+        result.push_str(&format!("{},{},{},{},{},{},{}\n",
+            report.year, report.day, report.part, verdict_name(verification), csv_field(&report.answer()),
+            csv_field(verification.expected.as_deref().unwrap_or_default()),
+            csv_field(&status_error(&report.status).unwrap_or_default())));
+    }
+    result
+}
+
+pub fn cross_check_to_json(reports: &[VariantReport]) -> String {
+    let entries: Vec<String> = reports.iter().map(|variant| {
+        let report = &variant.report;
+        let answer = match &report.status {
+            Status::Solved(_) => json_string(&report.answer()),
+            _ => "null".to_string(),
+        };
+        let error = status_error(&report.status).map(|e| json_string(&e)).unwrap_or_else(|| "null".to_string());
+        format!("{{\"year\":{},\"day\":{},\"part\":{},\"variant\":{},\"status\":\"{}\",\"answer\":{},\"elapsed_ns\":{},\"error\":{}}}",
+            report.year, report.day, report.part, json_string(variant.variant), status_name(&report.status), answer, nanoseconds(report.elapsed), error)
+    }).collect();
+
+    format!("{{\"variants\":[{}]}}", entries.join(","))
+}
+
+pub fn cross_check_to_csv(reports: &[VariantReport]) -> String {
+    let mut result = String::from("year,day,part,variant,status,answer,elapsed_ns,error\n");
+    for variant in reports {
+        let report = &variant.report;
+        result.push_str(&format!("{},{},{},{},{},{},{},{}\n",
+            report.year, report.day, report.part, csv_field(variant.variant), status_name(&report.status), csv_field(&report.answer()),
+            nanoseconds(report.elapsed), csv_field(&status_error(&report.status).unwrap_or_default())));
+    }
+    result
+}
+
+fn print_verification_table(verifications: &[Verification]) {
+    let rows: Vec<[String; 6]> = verifications.iter().map(|verification| [
+        verification.report.year.to_string(),
+        verification.report.day.to_string(),
+        verification.report.part.to_string(),
+        verification.report.answer(),
+        verification.expected.clone().unwrap_or_default(),
+        verification.verdict(),
+    ]).collect();
+
+    report::print_rows(["Year", "Day", "Part", "Answer", "Expected", "Result"], &rows);
+}
+
+pub fn print_reports(format: Format, reports: &[PartReport]) {
+    match format {
+        Format::Text => report::print_table(reports),
+        Format::Json => println!("{}", reports_to_json(reports)),
+        Format::Csv => print!("{}", reports_to_csv(reports)),
+    }
+}
+
+pub fn print_bench(format: Format, reports: &[BenchReport]) {
+    match format {
+        Format::Text => bench::print_table(reports),
+        Format::Json => println!("{}", bench_to_json(reports)),
+        Format::Csv => print!("{}", bench_to_csv(reports)),
+    }
+}
+
+pub fn print_cross_check(format: Format, reports: &[VariantReport]) {
+    match format {
+        Format::Text => cross_check::print_table(reports),
+        Format::Json => println!("{}", cross_check_to_json(reports)),
+        Format::Csv => print!("{}", cross_check_to_csv(reports)),
+    }
+}
+
+pub fn print_verification(format: Format, verifications: &[Verification]) {
+    match format {
+        Format::Text => print_verification_table(verifications),
+        Format::Json => println!("{}", verification_to_json(verifications)),
+        Format::Csv => print!("{}", verification_to_csv(verifications)),
+    }
+}
+
+#[test]
+pub fn run_test_escaping() {
+    assert_eq!(json_string("a \"b\"\n\\"), "\"a \\\"b\\\"\\n\\\\\"");
+    assert_eq!(csv_field("plain"), "plain");
+    assert_eq!(csv_field("a,\"b\""), "\"a,\"\"b\"\"\"");
+}
+
+#[test]
+pub fn run_test_reports() {
+    use crate::runner::answer::Answer;
+    use crate::runner::solution::Part;
+    use crate::utility::generic_error::GenericError;
+
+    let reports = [
+        PartReport { year: 2024, day: 1, part: Part::One, status: Status::Solved(Answer::Unsigned(11)), elapsed: Duration::from_nanos(1500) },
+        PartReport { year: 2024, day: 1, part: Part::Two, status: Status::Failed(GenericError::BasicError("bad, \"input\"".to_string())), elapsed: Duration::ZERO },
+    ];
+
+    assert_eq!(reports_to_json(&reports), concat!(
+        "{\"results\":[",
+        "{\"year\":2024,\"day\":1,\"part\":1,\"status\":\"ok\",\"answer\":\"11\",\"elapsed_ns\":1500,\"error\":null},",
+        "{\"year\":2024,\"day\":1,\"part\":2,\"status\":\"failed\",\"answer\":null,\"elapsed_ns\":0,\"error\":\"basic error: bad, \\\"input\\\"\"}",
+        "]}"));
+
+    assert_eq!(reports_to_csv(&reports), concat!(
+        "year,day,part,status,answer,elapsed_ns,error\n",
+        "2024,1,1,ok,11,1500,\n",
+        "2024,1,2,failed,,0,\"basic error: bad, \"\"input\"\"\"\n"));
+}
+
+#[test]
+pub fn run_test_cross_check() {
+    use crate::runner::answer::Answer;
+    use crate::runner::solution::Part;
+
+    let reports = [
+        VariantReport { variant: "fast", report: PartReport { year: 2024, day: 2, part: Part::One, status: Status::Solved(Answer::Unsigned(2)), elapsed: Duration::from_nanos(10) } },
+        VariantReport { variant: "naive", report: PartReport { year: 2024, day: 2, part: Part::One, status: Status::Solved(Answer::Unsigned(2)), elapsed: Duration::from_nanos(30) } },
+    ];
+
+    assert_eq!(cross_check_to_json(&reports), concat!(
+        "{\"variants\":[",
+        "{\"year\":2024,\"day\":2,\"part\":1,\"variant\":\"fast\",\"status\":\"ok\",\"answer\":\"2\",\"elapsed_ns\":10,\"error\":null},",
+        "{\"year\":2024,\"day\":2,\"part\":1,\"variant\":\"naive\",\"status\":\"ok\",\"answer\":\"2\",\"elapsed_ns\":30,\"error\":null}",
+        "]}"));
+
+    assert_eq!(cross_check_to_csv(&reports), concat!(
+        "year,day,part,variant,status,answer,elapsed_ns,error\n",
+        "2024,2,1,fast,ok,2,10,\n",
+        "2024,2,1,naive,ok,2,30,\n"));
+}
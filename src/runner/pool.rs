@@ -0,0 +1,41 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// Runs f over every item on up to `jobs` threads and returns the results in the original order
+pub fn map_in_parallel<T: Sync, R: Send>(items: &[T], jobs: usize, f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    let jobs = jobs.clamp(1, items.len().max(1));
+    if jobs == 1 {
+        return items.iter().map(f).collect();
+    }
+
+    let next_item = AtomicUsize::new(0);
+    let mut indexed_results: Vec<(usize, R)> = std::thread::scope(|scope| {
+        let workers: Vec<_> = (0..jobs).map(|_| scope.spawn(|| {
+            let mut results = Vec::new();
+            loop {
+                let index = next_item.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(index) else {
+                    break;
+                };
+                results.push((index, f(item)));
+            }
+            results
+        })).collect();
+
+        workers.into_iter().flat_map(|worker| worker.join().expect("Worker thread panicked")).collect()
+    });
+
+    indexed_results.sort_by_key(|(index, _)| *index);
+    indexed_results.into_iter().map(|(_, result)| result).collect()
+}
+
+#[test]
+pub fn run_test_order_is_preserved() {
+    let items: Vec<u64> = (0..50).collect();
+    let squares = map_in_parallel(&items, 4, |value| {
+        // Make later items finish first
+        std::thread::sleep(std::time::Duration::from_micros(50 - value));
+        value * value
+    });
+    assert_eq!(squares, items.iter().map(|value| value * value).collect::<Vec<u64>>());
+    assert!(map_in_parallel(&Vec::<u64>::new(), 4, |value| *value).is_empty());
+}
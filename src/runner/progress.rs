@@ -0,0 +1,152 @@
+use std::fmt::Debug;
+use std::io::{IsTerminal, Write};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::utility::log;
+
+// Receives progress from long running parts, see Context::progress
+pub trait Progress: Send + Sync + Debug {
+    fn start(&self, label: &str);
+    fn update(&self, done: usize, total: usize);
+    fn finish(&self);
+}
+
+const BAR_WIDTH: usize = 30;
+// Solvers may report on every step, redrawing that often would slow them down
+const REDRAW_INTERVAL: Duration = Duration::from_millis(100);
+
+#[derive(Debug, Default)]
+struct BarState {
+    label: String,
+    last_draw: Option<Instant>,
+}
+
+#[derive(Debug, Default)]
+pub struct ProgressBar {
+    state: Mutex<BarState>,
+}
+
+impl ProgressBar {
+    // None when stderr is redirected or diagnostics are turned down, nobody would see the bar then
+    pub fn for_stderr() -> Option<Arc<dyn Progress>> {
+        if !std::io::stderr().is_terminal() || !log::enabled(log::Level::Info) {
+            return None;
+        }
+        Some(Arc::new(ProgressBar::default()))
+    }
+}
+
+pub fn render(label: &str, done: usize, total: usize) -> String {
+    let fraction = if total == 0 { 1.0 } else { (done.min(total) as f64) / (total as f64) };
+    let filled = (fraction * BAR_WIDTH as f64).round() as usize;
+    format!("{} [{}{}] {:>3}% ({}/{})", label, "#".repeat(filled), ".".repeat(BAR_WIDTH - filled), (fraction * 100.0).floor(), done, total)
+}
+
+impl Progress for ProgressBar {
+    fn start(&self, label: &str) {
+        let mut state = self.state.lock().unwrap();
+        state.label = label.to_string();
+        state.last_draw = None;
+    }
+
+    fn update(&self, done: usize, total: usize) {
+        let mut state = self.state.lock().unwrap();
+        if state.last_draw.is_some_and(|last_draw| last_draw.elapsed() < REDRAW_INTERVAL) {
+            return;
+        }
+        state.last_draw = Some(Instant::now());
+
+        let mut stderr = std::io::stderr();
+        let _ = write!(stderr, "\r\x1b[2K{}", render(&state.label, done, total));
+        let _ = stderr.flush();
+    }
+
+    fn finish(&self) {
+        let mut state = self.state.lock().unwrap();
+        if state.last_draw.take().is_some() {
+            let _ = write!(std::io::stderr(), "\r\x1b[2K");
+        }
+    }
+}
+
+// Passes everything on until detached. A run that timed out keeps going in the background and
+// would otherwise draw over whatever the runner moved on to.
+#[derive(Debug)]
+pub struct DetachableProgress {
+    inner: Arc<dyn Progress>,
+    // Held while passing a call on, so nothing gets through once detach returns
+    attached: Mutex<bool>,
+}
+
+impl DetachableProgress {
+    pub fn new(inner: Arc<dyn Progress>) -> Arc<Self> {
+        Arc::new(DetachableProgress { inner, attached: Mutex::new(true) })
+    }
+
+    // Clears what the run left on screen
+    pub fn detach(&self) {
+        let mut attached = self.attached.lock().unwrap();
+        if *attached {
+            self.inner.finish();
+            *attached = false;
+        }
+    }
+
+    fn forward(&self, call: impl FnOnce(&dyn Progress)) {
+        let attached = self.attached.lock().unwrap();
+        if *attached {
+            call(self.inner.as_ref());
+        }
+    }
+}
+
+impl Progress for DetachableProgress {
+    fn start(&self, label: &str) {
+        self.forward(|progress| progress.start(label));
+    }
+
+    fn update(&self, done: usize, total: usize) {
+        self.forward(|progress| progress.update(done, total));
+    }
+
+    fn finish(&self) {
+        self.forward(|progress| progress.finish());
+    }
+}
+
+// Keeps every update, for checking what a solver reports
+#[cfg(test)]
+#[derive(Debug, Default)]
+pub(crate) struct RecordingProgress {
+    pub updates: Mutex<Vec<(usize, usize)>>,
+}
+
+#[cfg(test)]
+impl Progress for RecordingProgress {
+    fn start(&self, _label: &str) {}
+
+    fn update(&self, done: usize, total: usize) {
+        self.updates.lock().unwrap().push((done, total));
+    }
+
+    fn finish(&self) {}
+}
+
+#[test]
+pub fn run_test_render() {
+    assert_eq!(render("Day 6 part two", 0, 4), format!("Day 6 part two [{}]   0% (0/4)", ".".repeat(30)));
+    assert_eq!(render("Day 7", 1, 2), format!("Day 7 [{}{}]  50% (1/2)", "#".repeat(15), ".".repeat(15)));
+    assert_eq!(render("Day 7", 5, 2), format!("Day 7 [{}] 100% (5/2)", "#".repeat(30)));
+    assert_eq!(render("Empty", 0, 0), format!("Empty [{}] 100% (0/0)", "#".repeat(30)));
+}
+
+#[test]
+pub fn run_test_detachable_progress() {
+    let recording = Arc::new(RecordingProgress::default());
+    let progress = DetachableProgress::new(recording.clone());
+    progress.update(1, 2);
+    progress.detach();
+    progress.update(2, 2);
+    assert_eq!(*recording.updates.lock().unwrap(), vec![(1, 2)]);
+}
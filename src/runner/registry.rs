@@ -0,0 +1,44 @@
+use std::collections::BTreeMap;
+
+use crate::runner::solution::{DynSolution, Solution};
+use crate::utility::generic_error::{GenericError, GenericResult};
+
+#[derive(Default)]
+pub struct Registry {
+    solutions: BTreeMap<(u16, u16), Box<dyn DynSolution>>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn register<S: Solution + 'static>(&mut self, solution: S) where S::Input: 'static {
+        let metadata = solution.metadata();
+        let previous = self.solutions.insert((metadata.year, metadata.day), Box::new(solution));
+        assert!(previous.is_none(), "Day {} of {} registered twice", metadata.day, metadata.year);
+    }
+
+    pub fn get(&self, year: u16, day: u16) -> Option<&dyn DynSolution> {
+        self.solutions.get(&(year, day)).map(|s| s.as_ref())
+    }
+
+    pub fn find(&self, year: u16, day: u16) -> GenericResult<&dyn DynSolution> {
+        let Some(solution) = self.get(year, day) else {
+            let available_days: Vec<String> = self.iter_year(year).map(|s| s.metadata().day.to_string()).collect();
+            return Err(GenericError::IOError(std::io::Error::new(std::io::ErrorKind::InvalidInput,
+                format!("Unknown day {} of {} (available days: {})", day, year, available_days.join(", ")))));
+        };
+        Ok(solution)
+    }
+
+    pub fn iter_year(&self, year: u16) -> impl Iterator<Item=&dyn DynSolution> {
+        self.solutions.range((year, 0)..=(year, u16::MAX)).map(|(_, s)| s.as_ref())
+    }
+
+    pub fn years(&self) -> Vec<u16> {
+        let mut years: Vec<u16> = self.solutions.keys().map(|(year, _)| *year).collect();
+        years.dedup();
+        years
+    }
+}
@@ -0,0 +1,173 @@
+use std::path::{Path, PathBuf};
+
+use crate::runner::answer::Answer;
+use crate::runner::solution::Part;
+use crate::utility::generic_error::{GenericError, GenericResult};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+pub fn default_session_path() -> Option<PathBuf> {
+    std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config").join("aoc").join("session"))
+}
+
+// The environment variable wins over the file so a token can be swapped in for one run
+pub fn session_token(session_path: Option<&Path>) -> GenericResult<String> {
+    if let Ok(token) = std::env::var(SESSION_ENV) {
+        if !token.trim().is_empty() {
+            return Ok(token.trim().to_string());
+        }
+    }
+
+    let Some(session_path) = session_path else {
+        return Err(GenericError::BasicError(format!("No session token, set {} or write it to a session file", SESSION_ENV)));
+    };
+    match std::fs::read_to_string(session_path) {
+        Ok(token) if !token.trim().is_empty() => Ok(token.trim().to_string()),
+        Ok(_) => Err(GenericError::BasicError(format!("Session file {} is empty", session_path.display()))),
+        Err(e) => Err(GenericError::BasicError(format!("No session token, set {} or write it to {}: {}", SESSION_ENV, session_path.display(), e))),
+    }
+}
+
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: String) -> Self {
+        let agent = ureq::AgentBuilder::new().user_agent(USER_AGENT).build();
+        Client { base_url: base_url.trim_end_matches('/').to_string(), session, agent }
+    }
+
+    fn day_url(&self, year: u16, day: u16) -> String {
+        format!("{}/{}/day/{}", self.base_url, year, day)
+    }
+
+    pub fn download_input(&self, year: u16, day: u16) -> GenericResult<String> {
+        let response = self.agent.get(&format!("{}/input", self.day_url(year, day)))
+            .set("Cookie", &format!("session={}", self.session))
+            .call()?;
+        Ok(response.into_string()?)
+    }
+
+    // Returns the page the server answers with, it has to be read to know whether the answer was right
+    pub fn submit_answer(&self, year: u16, day: u16, part: Part, answer: &Answer) -> GenericResult<String> {
+        let response = self.agent.post(&format!("{}/answer", self.day_url(year, day)))
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", &answer.to_string())])?;
+        Ok(response.into_string()?)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Cached,
+    Downloaded,
+}
+
+// Inputs never change once published, so an existing file is never downloaded again. An empty
+// file is the placeholder new-day leaves behind and doesn't count.
+// The client is only created on a cache miss, which keeps a missing token from mattering.
+pub fn fetch_input(path: &Path, year: u16, day: u16, client: impl FnOnce() -> GenericResult<Client>) -> GenericResult<Fetched> {
+    if std::fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0) {
+        return Ok(Fetched::Cached);
+    }
+
+    let input = client()?.download_input(year, day)?;
+    if let Some(directory) = path.parent() {
+        std::fs::create_dir_all(directory)?;
+    }
+    std::fs::write(path, input)?;
+    Ok(Fetched::Downloaded)
+}
+
+// Answers a single request with the given status and body, the request is returned from the thread
+#[cfg(test)]
+pub(crate) fn serve_once(status: &'static str, body: &'static str) -> std::io::Result<(String, std::thread::JoinHandle<String>)> {
+    use std::io::{BufRead, BufReader, Read, Write};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+    let base_url = format!("http://{}", listener.local_addr()?);
+    let handle = std::thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream);
+        let mut request = String::new();
+        let mut content_length = 0;
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            if let Some((name, value)) = line.split_once(':') {
+                if name.eq_ignore_ascii_case("content-length") {
+                    content_length = value.trim().parse().unwrap();
+                }
+            }
+            request.push_str(&line);
+            if line.trim().is_empty() {
+                break;
+            }
+        }
+        let mut content = vec![0; content_length];
+        reader.read_exact(&mut content).unwrap();
+        request.push_str(&String::from_utf8(content).unwrap());
+
+        let mut stream = reader.into_inner();
+        write!(stream, "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", status, body.len(), body).unwrap();
+        request
+    });
+    Ok((base_url, handle))
+}
+
+#[test]
+pub fn run_test_fetch_input() -> GenericResult<()> {
+    let directory = std::env::temp_dir().join(format!("aoc_fetch_test_{}", std::process::id()));
+    let path = directory.join("day3").join("input.txt");
+
+    let (base_url, server) = serve_once("200 OK", "xmul(2,4)\n")?;
+    let fetched = fetch_input(&path, 2024, 3, || Ok(Client::new(&base_url, "abc".to_string())))?;
+    assert_eq!(fetched, Fetched::Downloaded);
+    assert_eq!(std::fs::read_to_string(&path)?, "xmul(2,4)\n");
+
+    let request = server.join().unwrap();
+    assert!(request.starts_with("GET /2024/day/3/input HTTP/1.1\r\n"));
+    assert!(request.contains("session=abc"));
+
+    // A cached input is returned without asking for a client
+    let fetched = fetch_input(&path, 2024, 3, || Err(GenericError::BasicError("should not connect".to_string())))?;
+    assert_eq!(fetched, Fetched::Cached);
+
+    std::fs::remove_dir_all(&directory)?;
+    Ok(())
+}
+
+#[test]
+pub fn run_test_fetch_replaces_placeholder() -> GenericResult<()> {
+    let directory = std::env::temp_dir().join(format!("aoc_fetch_placeholder_test_{}", std::process::id()));
+    let path = directory.join("input.txt");
+    std::fs::create_dir_all(&directory)?;
+    std::fs::write(&path, "")?;
+
+    let (base_url, server) = serve_once("200 OK", "3   4\n")?;
+    let fetched = fetch_input(&path, 2024, 1, || Ok(Client::new(&base_url, "abc".to_string())))?;
+    assert_eq!(fetched, Fetched::Downloaded);
+    assert_eq!(std::fs::read_to_string(&path)?, "3   4\n");
+
+    server.join().unwrap();
+    std::fs::remove_dir_all(&directory)?;
+    Ok(())
+}
+
+#[test]
+pub fn run_test_fetch_error() -> GenericResult<()> {
+    let path = std::env::temp_dir().join(format!("aoc_fetch_error_test_{}", std::process::id())).join("input.txt");
+
+    let (base_url, server) = serve_once("404 Not Found", "Not yet unlocked")?;
+    assert!(fetch_input(&path, 2024, 25, || Ok(Client::new(&base_url, "abc".to_string()))).is_err());
+    assert!(!path.exists());
+
+    server.join().unwrap();
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use std::time::Duration;
+
+use crate::runner::answer::Answer;
+use crate::runner::solution::Part;
+use crate::utility::generic_error::GenericError;
+
+#[derive(Debug)]
+pub enum Status {
+    Solved(Answer),
+    Skipped(GenericError),
+    Failed(GenericError),
+}
+
+#[derive(Debug)]
+pub struct PartReport {
+    pub year: u16,
+    pub day: u16,
+    pub part: Part,
+    pub status: Status,
+    pub elapsed: Duration,
+}
+
+impl PartReport {
+    pub fn answer(&self) -> String {
+        match &self.status {
+            Status::Solved(answer) => answer.to_string(),
+            _ => String::new(),
+        }
+    }
+
+    pub fn status(&self) -> String {
+        match &self.status {
+            Status::Solved(_) => "ok".to_string(),
+            Status::Skipped(reason) => format!("skipped: {}", reason),
+            Status::Failed(error) => format!("failed: {}", error),
+        }
+    }
+}
+
+pub fn print_rows<const N: usize>(header: [&str; N], rows: &[[String; N]]) {
+    let mut widths = header.map(|title| title.len());
+    for row in rows.iter() {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    let format_row = |cells: &[String]| -> String {
+        let padded: Vec<String> = cells.iter().zip(widths.iter()).map(|(cell, width)| format!("{:<width$}", cell, width = width)).collect();
+        padded.join(" | ").trim_end().to_string()
+    };
+
+    println!("{}", format_row(&header.map(|title| title.to_string())));
+    println!("{}", widths.map(|width| "-".repeat(width)).join("-+-"));
+    for row in rows.iter() {
+        println!("{}", format_row(row));
+    }
+}
+
+pub fn print_table(reports: &[PartReport]) {
+    let rows: Vec<[String; 6]> = reports.iter().map(|report| [
+        report.year.to_string(),
+        report.day.to_string(),
+        report.part.to_string(),
+        report.answer(),
+        format!("{:.3?}", report.elapsed),
+        report.status(),
+    ]).collect();
+
+    print_rows(["Year", "Day", "Part", "Answer", "Time", "Status"], &rows);
+}
@@ -0,0 +1,212 @@
+use std::path::{Path, PathBuf};
+
+use crate::runner::input;
+use crate::utility::generic_error::{GenericError, GenericResult};
+
+const DAY_TEMPLATE: &str = r#"use crate::runner::answer::Answer;
+use crate::runner::context::Context;
+use crate::runner::solution::{Metadata, Solution};
+use crate::utility::generic_error::{GenericError, GenericResult};
+
+fn load_lines(input : &str) -> GenericResult<Vec<String>> {
+    Ok(input.lines().map(|line| line.to_string()).collect())
+}
+
+fn part_1(_lines : &[String]) -> GenericResult<Answer> {
+    Err(GenericError::BasicError("Part one is not implemented yet".to_string()))
+}
+
+fn part_2(_lines : &[String]) -> GenericResult<Answer> {
+    Err(GenericError::BasicError("Part two is not implemented yet".to_string()))
+}
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    type Input = Vec<String>;
+
+    fn metadata(&self) -> Metadata {
+        Metadata { year: {year}, day: {day}, title: "{title}" }
+    }
+
+    fn parse(&self, input: &str) -> GenericResult<Self::Input> {
+        load_lines(input)
+    }
+
+    fn part_1(&self, input: &Self::Input, _context: &Context) -> GenericResult<Answer> {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input, _context: &Context) -> GenericResult<Answer> {
+        part_2(input)
+    }
+}
+
+#[test]
+#[ignore = "fill in the example answer"]
+pub fn run_test_1() -> GenericResult<()> {
+    let lines = Day{day}.parse(&std::fs::read_to_string("data/{year}/day{day}/example.txt")?)?;
+    assert_eq!(Day{day}.part_1(&lines, &Context::default())?, Answer::Unsigned(0));
+    Ok(())
+}
+
+#[test]
+#[ignore = "fill in the example answer"]
+pub fn run_test_2() -> GenericResult<()> {
+    let lines = Day{day}.parse(&std::fs::read_to_string("data/{year}/day{day}/example.txt")?)?;
+    assert_eq!(Day{day}.part_2(&lines, &Context::default())?, Answer::Unsigned(0));
+    Ok(())
+}
+"#;
+
+const YEAR_TEMPLATE: &str = r#"use crate::runner::registry::Registry;
+
+pub mod day{day};
+
+pub fn register(registry: &mut Registry) {
+    registry.register(day{day}::Day{day});
+}
+"#;
+
+fn render_year(day: u16) -> String {
+    YEAR_TEMPLATE.replace("{day}", &day.to_string())
+}
+
+fn render_day(year: u16, day: u16, title: &str) -> String {
+    DAY_TEMPLATE
+        .replace("{year}", &year.to_string())
+        .replace("{day}", &day.to_string())
+        .replace("{title}", &title.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+// Inserts new_line among the lines that parse_number recognises, keeping them in numeric order
+// and matching their indentation
+fn insert_sorted_line(source: &str, parse_number: impl Fn(&str) -> Option<u16>, number: u16, new_line: &str) -> GenericResult<String> {
+    let mut lines: Vec<String> = source.lines().map(|line| line.to_string()).collect();
+
+    let mut insert_at = None;
+    for (index, line) in lines.iter().enumerate() {
+        let Some(existing) = parse_number(line.trim()) else {
+            continue;
+        };
+
+        if existing == number {
+            return Err(GenericError::BasicError(format!("'{}' is already present", line.trim())));
+        }
+
+        if existing < number {
+            insert_at = Some((index + 1, index));
+        } else if insert_at.is_none() {
+            insert_at = Some((index, index));
+        }
+    }
+
+    let Some((insert_at, neighbour)) = insert_at else {
+        return Err(GenericError::BasicError(format!("Could not find where to insert '{}'", new_line)));
+    };
+
+    let indent: String = lines[neighbour].chars().take_while(|c| c.is_whitespace()).collect();
+    lines.insert(insert_at, format!("{}{}", indent, new_line));
+    Ok(lines.join("\n") + "\n")
+}
+
+fn parse_between<'a>(line: &'a str, prefix: &str, suffix: &str) -> Option<&'a str> {
+    line.strip_prefix(prefix)?.strip_suffix(suffix)
+}
+
+fn register_day(year_module: &str, day: u16) -> GenericResult<String> {
+    let with_module = insert_sorted_line(year_module,
+        |line| parse_between(line, "pub mod day", ";")?.parse().ok(),
+        day, &format!("pub mod day{};", day))?;
+    insert_sorted_line(&with_module,
+        |line| parse_between(line, "registry.register(day", ");")?.split("::").next()?.parse().ok(),
+        day, &format!("registry.register(day{}::Day{});", day, day))
+}
+
+fn register_year(implementations_module: &str, year: u16) -> GenericResult<String> {
+    let with_module = insert_sorted_line(implementations_module,
+        |line| parse_between(line, "pub mod year", ";")?.parse().ok(),
+        year, &format!("pub mod year{};", year))?;
+    insert_sorted_line(&with_module,
+        |line| parse_between(line, "year", "::register(&mut registry);")?.parse().ok(),
+        year, &format!("year{}::register(&mut registry);", year))
+}
+
+fn create_new_file(path: &Path, content: &str) -> GenericResult<()> {
+    // create_new fails if the file is already there, so nothing is ever overwritten
+    let mut file = std::fs::OpenOptions::new().write(true).create_new(true).open(path)
+        .map_err(|e| std::io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))?;
+    std::io::Write::write_all(&mut file, content.as_bytes())?;
+    Ok(())
+}
+
+// Returns every file that was created or modified
+pub fn new_day(source_root: &Path, year: u16, day: u16, title: &str) -> GenericResult<Vec<PathBuf>> {
+    let implementations_directory = source_root.join("src").join("implementations");
+    let year_directory = implementations_directory.join(format!("year{}", year));
+    let day_source = year_directory.join(format!("day{}.rs", day));
+    // Sources go in the crate, but the data goes wherever fetch and the runs will look for it
+    let data_directory = std::path::absolute(input::day_directory(year, day))?;
+
+    if day_source.exists() {
+        return Err(GenericError::BasicError(format!("{} already exists", day_source.display())));
+    }
+    if data_directory.exists() {
+        return Err(GenericError::BasicError(format!("{} already exists", data_directory.display())));
+    }
+
+    let mut changed = Vec::new();
+
+    let year_module = year_directory.join("mod.rs");
+    let updated_year_source = if year_module.exists() {
+        register_day(&std::fs::read_to_string(&year_module)?, day)?
+    } else {
+        // Register the year first so a failure doesn't leave an orphaned directory behind
+        let implementations_module = implementations_directory.join("mod.rs");
+        let updated = register_year(&std::fs::read_to_string(&implementations_module)?, year)?;
+        std::fs::create_dir_all(&year_directory)?;
+        std::fs::write(&implementations_module, updated)?;
+        changed.push(implementations_module);
+        render_year(day)
+    };
+
+    create_new_file(&day_source, &render_day(year, day, title))?;
+    changed.push(day_source);
+    std::fs::write(&year_module, updated_year_source)?;
+    changed.push(year_module);
+
+    std::fs::create_dir_all(&data_directory)?;
+    for placeholder in ["example.txt", "input.txt"] {
+        let path = data_directory.join(placeholder);
+        create_new_file(&path, "")?;
+        changed.push(path);
+    }
+
+    Ok(changed)
+}
+
+#[test]
+pub fn run_test_register_day() -> GenericResult<()> {
+    let year_module = "use crate::runner::registry::Registry;\n\npub mod day1;\npub mod day3;\n\npub fn register(registry: &mut Registry) {\n    registry.register(day1::Day1);\n    registry.register(day3::Day3);\n}\n";
+    assert_eq!(register_day(year_module, 2)?, "use crate::runner::registry::Registry;\n\npub mod day1;\npub mod day2;\npub mod day3;\n\npub fn register(registry: &mut Registry) {\n    registry.register(day1::Day1);\n    registry.register(day2::Day2);\n    registry.register(day3::Day3);\n}\n");
+    assert!(register_day(year_module, 3).is_err());
+    assert_eq!(register_day(&render_year(1), 4)?, year_module.replace("3", "4"));
+    Ok(())
+}
+
+#[test]
+pub fn run_test_register_year() -> GenericResult<()> {
+    let implementations_module = std::fs::read_to_string("src/implementations/mod.rs")?;
+    let registered = register_year(&implementations_module, 2015)?;
+    assert!(registered.contains("pub mod year2015;\npub mod year2024;"));
+    assert!(registered.contains("    year2015::register(&mut registry);\n    year2024::register(&mut registry);"));
+    Ok(())
+}
+
+#[test]
+pub fn run_test_render_day() {
+    let rendered = render_day(2024, 9, "Disk \"Fragmenter\"");
+    assert!(rendered.contains("pub struct Day9;"));
+    assert!(rendered.contains("Metadata { year: 2024, day: 9, title: \"Disk \\\"Fragmenter\\\"\" }"));
+    assert!(rendered.contains("\"data/2024/day9/example.txt\""));
+}
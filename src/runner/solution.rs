@@ -0,0 +1,121 @@
+use std::any::Any;
+use std::fmt::{Display, Formatter, Error as FmtError};
+
+use crate::runner::answer::Answer;
+use crate::runner::context::Context;
+use crate::utility::generic_error::{GenericError, GenericResult};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, clap::ValueEnum)]
+pub enum Part {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Part::One => "one",
+            Part::Two => "two",
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), FmtError> {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Metadata {
+    pub year: u16,
+    pub day: u16,
+    pub title: &'static str,
+}
+
+pub const DEFAULT_VARIANT: &str = "default";
+
+pub trait Solution: Send + Sync {
+    type Input;
+
+    fn metadata(&self) -> Metadata;
+    fn parse(&self, input: &str) -> GenericResult<Self::Input>;
+    fn part_1(&self, input: &Self::Input, context: &Context) -> GenericResult<Answer>;
+    fn part_2(&self, input: &Self::Input, context: &Context) -> GenericResult<Answer>;
+
+    // Names of the implementations of a part. The first is the one part_1 and part_2 run,
+    // the others are run through solve_variant.
+    fn variants(&self, _part: Part) -> &'static [&'static str] {
+        &[DEFAULT_VARIANT]
+    }
+
+    // Keys this day reads through Context::param, any other --param is rejected before running
+    fn params(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    fn solve_variant(&self, part: Part, variant: &str, _input: &Self::Input, _context: &Context) -> GenericResult<Answer> {
+        Err(GenericError::BasicError(format!("Variant {} of part {} is not implemented", variant, part.name())))
+    }
+
+    fn solve(&self, part: Part, input: &Self::Input, context: &Context) -> GenericResult<Answer> {
+        let variants = self.variants(part);
+        match context.variant() {
+            Some(variant) if variant != variants[0] && variant != DEFAULT_VARIANT => {
+                if !variants.contains(&variant) {
+                    let metadata = self.metadata();
+                    return Err(GenericError::BasicError(format!("Unknown variant {} for part {} of day {} of {} (available variants: {})",
+                        variant, part.name(), metadata.day, metadata.year, variants.join(", "))));
+                }
+                self.solve_variant(part, variant, input, context)
+            }
+            _ => match part {
+                Part::One => self.part_1(input, context),
+                Part::Two => self.part_2(input, context),
+            },
+        }
+    }
+}
+
+// Solution has an associated input type, so the registry stores days through this
+// object safe view instead. The parsed input is passed back to solve as-is.
+pub trait DynSolution: Send + Sync {
+    fn metadata(&self) -> Metadata;
+    fn parse(&self, input: &str) -> GenericResult<Box<dyn Any>>;
+    fn variants(&self, part: Part) -> &'static [&'static str];
+    fn params(&self) -> &'static [&'static str];
+    fn solve(&self, part: Part, input: &dyn Any, context: &Context) -> GenericResult<Answer>;
+}
+
+impl<S: Solution> DynSolution for S where S::Input: 'static {
+    fn metadata(&self) -> Metadata {
+        Solution::metadata(self)
+    }
+
+    fn parse(&self, input: &str) -> GenericResult<Box<dyn Any>> {
+        Ok(Box::new(Solution::parse(self, input)?))
+    }
+
+    fn variants(&self, part: Part) -> &'static [&'static str] {
+        Solution::variants(self, part)
+    }
+
+    fn params(&self) -> &'static [&'static str] {
+        Solution::params(self)
+    }
+
+    fn solve(&self, part: Part, input: &dyn Any, context: &Context) -> GenericResult<Answer> {
+        let Some(input) = input.downcast_ref::<S::Input>() else {
+            let metadata = Solution::metadata(self);
+            return Err(GenericError::BasicError(format!("Input for day {} of {} was not parsed by its own solution", metadata.day, metadata.year)));
+        };
+        Solution::solve(self, part, input, context)
+    }
+}
@@ -0,0 +1,198 @@
+use std::fmt::{Display, Formatter, Error as FmtError};
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::runner::answer::Answer;
+use crate::runner::input::day_directory;
+use crate::runner::remote::Client;
+use crate::runner::solution::Part;
+use crate::utility::generic_error::{GenericError, GenericResult};
+
+pub fn history_path(year: u16, day: u16) -> PathBuf {
+    day_directory(year, day).join("submissions.toml")
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    RateLimited,
+    // The part was already solved, or part one isn't solved yet
+    WrongLevel,
+    Unknown,
+}
+
+impl Outcome {
+    pub fn is_rejection(&self) -> bool {
+        matches!(self, Outcome::Incorrect | Outcome::TooHigh | Outcome::TooLow)
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), FmtError> {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::Incorrect => write!(f, "incorrect"),
+            Outcome::TooHigh => write!(f, "incorrect, too high"),
+            Outcome::TooLow => write!(f, "incorrect, too low"),
+            Outcome::RateLimited => write!(f, "rate limited, wait before submitting again"),
+            Outcome::WrongLevel => write!(f, "not accepted, this part is already solved or not unlocked yet"),
+            Outcome::Unknown => write!(f, "unrecognised response"),
+        }
+    }
+}
+
+pub fn parse_outcome(page: &str) -> Outcome {
+    if page.contains("That's the right answer") {
+        Outcome::Correct
+    } else if page.contains("You gave an answer too recently") {
+        Outcome::RateLimited
+    } else if page.contains("You don't seem to be solving the right level") {
+        Outcome::WrongLevel
+    } else if page.contains("That's not the right answer") {
+        if page.contains("your answer is too high") {
+            Outcome::TooHigh
+        } else if page.contains("your answer is too low") {
+            Outcome::TooLow
+        } else {
+            Outcome::Incorrect
+        }
+    } else {
+        Outcome::Unknown
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub answer: String,
+    pub outcome: Outcome,
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct History {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    part1: Vec<Submission>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    part2: Vec<Submission>,
+}
+
+impl History {
+    pub fn load(year: u16, day: u16) -> GenericResult<History> {
+        match std::fs::read_to_string(history_path(year, day)) {
+            Ok(content) => Ok(toml::from_str(&content)?),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Default::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn save(&self, year: u16, day: u16) -> GenericResult<()> {
+        std::fs::write(history_path(year, day), toml::to_string(self)?)?;
+        Ok(())
+    }
+
+    pub fn get(&self, part: Part) -> &[Submission] {
+        match part {
+            Part::One => &self.part1,
+            Part::Two => &self.part2,
+        }
+    }
+
+    pub fn record(&mut self, part: Part, answer: &Answer, outcome: Outcome) {
+        let submission = Submission { answer: answer.to_string(), outcome };
+        match part {
+            Part::One => self.part1.push(submission),
+            Part::Two => self.part2.push(submission),
+        }
+    }
+
+    // Why the answer can't be right according to earlier submissions, if it can't
+    pub fn ruled_out(&self, part: Part, answer: &Answer) -> Option<String> {
+        // Compared as written, parsing "0123" back would turn it into the number 123
+        let written = answer.to_string();
+        for submission in self.get(part) {
+            if submission.outcome == Outcome::Correct {
+                return Some(format!("part {} was already solved with {}", part.name(), submission.answer));
+            }
+            if submission.outcome.is_rejection() && submission.answer == written {
+                return Some(format!("{} was already rejected as {}", answer, submission.outcome));
+            }
+
+            let Ok(submitted) = submission.answer.parse::<Answer>();
+            let (Some(bound), Some(value)) = (submitted.as_integer(), answer.as_integer()) else {
+                continue;
+            };
+            match submission.outcome {
+                Outcome::TooHigh if value >= bound => return Some(format!("{} was too high, so {} is too", bound, value)),
+                Outcome::TooLow if value <= bound => return Some(format!("{} was too low, so {} is too", bound, value)),
+                _ => {},
+            }
+        }
+        None
+    }
+}
+
+// Checks the history before connecting and records whatever the server says about the answer
+pub fn submit(history: &mut History, year: u16, day: u16, part: Part, answer: &Answer, client: impl FnOnce() -> GenericResult<Client>) -> GenericResult<Outcome> {
+    if let Some(reason) = history.ruled_out(part, answer) {
+        return Err(GenericError::BasicError(format!("Not submitting {} for part {}: {}", answer, part.name(), reason)));
+    }
+
+    let page = client()?.submit_answer(year, day, part, answer)?;
+    let outcome = parse_outcome(&page);
+    history.record(part, answer, outcome);
+    Ok(outcome)
+}
+
+#[test]
+pub fn run_test_parse_outcome() {
+    assert_eq!(parse_outcome("<article><p>That's the right answer!  You are one gold star closer"), Outcome::Correct);
+    assert_eq!(parse_outcome("<article><p>That's not the right answer; your answer is too high."), Outcome::TooHigh);
+    assert_eq!(parse_outcome("<article><p>That's not the right answer; your answer is too low."), Outcome::TooLow);
+    assert_eq!(parse_outcome("<article><p>That's not the right answer.  If you're stuck"), Outcome::Incorrect);
+    assert_eq!(parse_outcome("<article><p>You gave an answer too recently; you have to wait"), Outcome::RateLimited);
+    assert_eq!(parse_outcome("<article><p>You don't seem to be solving the right level."), Outcome::WrongLevel);
+    assert_eq!(parse_outcome("<html></html>"), Outcome::Unknown);
+}
+
+#[test]
+pub fn run_test_ruled_out() -> GenericResult<()> {
+    let history: History = toml::from_str("[[part1]]\nanswer = \"100\"\noutcome = \"too_high\"\n\n[[part1]]\nanswer = \"10\"\noutcome = \"too_low\"\n\n[[part1]]\nanswer = \"50\"\noutcome = \"incorrect\"\n\n[[part1]]\nanswer = \"60\"\noutcome = \"rate_limited\"\n")?;
+    assert!(history.ruled_out(Part::One, &Answer::Unsigned(100)).is_some());
+    assert!(history.ruled_out(Part::One, &Answer::Unsigned(150)).is_some());
+    assert!(history.ruled_out(Part::One, &Answer::Signed(-5)).is_some());
+    assert!(history.ruled_out(Part::One, &Answer::Unsigned(50)).is_some());
+    assert_eq!(history.ruled_out(Part::One, &Answer::Unsigned(60)), None);
+    assert_eq!(history.ruled_out(Part::One, &Answer::Unsigned(42)), None);
+    assert_eq!(history.ruled_out(Part::Two, &Answer::Unsigned(100)), None);
+
+    let history: History = toml::from_str("[[part1]]\nanswer = \"0123\"\noutcome = \"incorrect\"\n")?;
+    assert!(history.ruled_out(Part::One, &Answer::from("0123")).is_some());
+    assert_eq!(history.ruled_out(Part::One, &Answer::Unsigned(123)), None);
+    Ok(())
+}
+
+#[test]
+pub fn run_test_submit() -> GenericResult<()> {
+    use crate::runner::remote::serve_once;
+
+    let mut history = History::default();
+    let (base_url, server) = serve_once("200 OK", "<article><p>That's not the right answer; your answer is too low.</p></article>")?;
+    let outcome = submit(&mut history, 2024, 7, Part::Two, &Answer::Unsigned(42), || Ok(Client::new(&base_url, "abc".to_string())))?;
+    assert_eq!(outcome, Outcome::TooLow);
+
+    let request = server.join().unwrap();
+    assert!(request.starts_with("POST /2024/day/7/answer HTTP/1.1\r\n"));
+    assert!(request.ends_with("level=2&answer=42"));
+    assert_eq!(history.get(Part::Two), [Submission { answer: "42".to_string(), outcome: Outcome::TooLow }]);
+
+    // Ruled out answers are refused before a client is even created
+    let connect = || -> GenericResult<Client> { panic!("should not connect") };
+    assert!(submit(&mut history, 2024, 7, Part::Two, &Answer::Unsigned(42), connect).is_err());
+    assert!(submit(&mut history, 2024, 7, Part::Two, &Answer::Unsigned(41), connect).is_err());
+    assert_eq!(history.get(Part::Two).len(), 1);
+    Ok(())
+}
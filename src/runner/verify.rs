@@ -0,0 +1,140 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::runner::answer::Answer;
+use crate::runner::input::day_directory;
+use crate::runner::report::{PartReport, Status};
+use crate::runner::solution::Part;
+use crate::utility::generic_error::GenericResult;
+
+pub fn answers_path(year: u16, day: u16) -> PathBuf {
+    day_directory(year, day).join("answers.toml")
+}
+
+// Answers are stored as strings so the file doesn't care what type a part returns
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2: Option<String>,
+}
+
+impl Answers {
+    pub fn load(year: u16, day: u16) -> GenericResult<Answers> {
+        match std::fs::read_to_string(answers_path(year, day)) {
+            Ok(content) => Ok(toml::from_str(&content)?),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Default::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn save(&self, year: u16, day: u16) -> GenericResult<()> {
+        std::fs::write(answers_path(year, day), toml::to_string(self)?)?;
+        Ok(())
+    }
+
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+
+    pub fn set(&mut self, part: Part, answer: &Answer) {
+        match part {
+            Part::One => self.part1 = Some(answer.to_string()),
+            Part::Two => self.part2 = Some(answer.to_string()),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Match,
+    Mismatch,
+    NoStoredAnswer,
+    NotSolved,
+}
+
+#[derive(Debug)]
+pub struct Verification {
+    pub report: PartReport,
+    pub expected: Option<String>,
+    pub verdict: Verdict,
+}
+
+impl Verification {
+    pub fn new(report: PartReport, answers: &Answers) -> Self {
+        let expected = answers.get(report.part).map(|expected| expected.to_string());
+        // Compared as written, parsing the stored string back can't tell the text "007" from the number 7
+        let verdict = match (&report.status, &expected) {
+            (Status::Solved(_), None) => Verdict::NoStoredAnswer,
+            (Status::Solved(answer), Some(expected)) if answer.to_string() == *expected => Verdict::Match,
+            (Status::Solved(_), Some(_)) => Verdict::Mismatch,
+            _ => Verdict::NotSolved,
+        };
+        Verification { report, expected, verdict }
+    }
+
+    // A part skipped for want of an input only fails when its day was asked for by name,
+    // a whole year is allowed to have days that aren't set up yet
+    pub fn is_failure(&self, explicit: bool) -> bool {
+        match (&self.verdict, &self.report.status) {
+            (Verdict::Mismatch, _) => true,
+            (Verdict::NotSolved, Status::Failed(_)) => true,
+            (Verdict::NotSolved, Status::Skipped(_)) => explicit,
+            _ => false,
+        }
+    }
+
+    pub fn was_compared(&self) -> bool {
+        matches!(self.verdict, Verdict::Match | Verdict::Mismatch)
+    }
+
+    pub fn verdict(&self) -> String {
+        match self.verdict {
+            Verdict::Match => "match".to_string(),
+            Verdict::Mismatch => "MISMATCH".to_string(),
+            Verdict::NoStoredAnswer => "no stored answer".to_string(),
+            Verdict::NotSolved => self.report.status(),
+        }
+    }
+}
+
+#[test]
+pub fn run_test_verification() -> GenericResult<()> {
+    use std::time::Duration;
+
+    let answers: Answers = toml::from_str("part1 = \"11\"\n")?;
+    let solved = |part, answer| PartReport { year: 2024, day: 1, part, status: Status::Solved(Answer::Unsigned(answer)), elapsed: Duration::ZERO };
+
+    assert_eq!(Verification::new(solved(Part::One, 11), &answers).verdict, Verdict::Match);
+    assert_eq!(Verification::new(solved(Part::One, 12), &answers).verdict, Verdict::Mismatch);
+    assert_eq!(Verification::new(solved(Part::Two, 31), &answers).verdict, Verdict::NoStoredAnswer);
+    assert!(!Verification::new(solved(Part::Two, 31), &answers).was_compared());
+
+    for text in ["123", "007"] {
+        let mut answers = Answers::default();
+        answers.set(Part::One, &Answer::from(text));
+        let report = PartReport { year: 2024, day: 1, part: Part::One, status: Status::Solved(Answer::from(text)), elapsed: Duration::ZERO };
+        assert_eq!(Verification::new(report, &answers).verdict, Verdict::Match);
+    }
+
+    let missing = PartReport { year: 2024, day: 1, part: Part::One, status: Status::Skipped(std::io::Error::from(std::io::ErrorKind::NotFound).into()), elapsed: Duration::ZERO };
+    let skipped = Verification::new(missing, &answers);
+    assert!(skipped.is_failure(true));
+    assert!(!skipped.is_failure(false));
+    Ok(())
+}
+
+#[test]
+pub fn run_test_answers_round_trip() -> GenericResult<()> {
+    let mut answers = Answers::default();
+    answers.set(Part::Two, &Answer::Unsigned(31));
+    let written = toml::to_string(&answers)?;
+    assert_eq!(written, "part2 = \"31\"\n");
+    assert_eq!(toml::from_str::<Answers>(&written)?, answers);
+    Ok(())
+}
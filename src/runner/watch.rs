@@ -0,0 +1,62 @@
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+pub const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+// Plain polling of modification time and size, so it works without inotify or any other service
+pub struct Watcher {
+    paths: Vec<PathBuf>,
+    last_seen: Vec<Option<(SystemTime, u64)>>,
+}
+
+impl Watcher {
+    pub fn new(paths: Vec<PathBuf>) -> Self {
+        let last_seen = Self::snapshot(&paths);
+        Watcher { paths, last_seen }
+    }
+
+    fn snapshot(paths: &[PathBuf]) -> Vec<Option<(SystemTime, u64)>> {
+        paths.iter().map(|path| {
+            let metadata = std::fs::metadata(path).ok()?;
+            Some((metadata.modified().ok()?, metadata.len()))
+        }).collect()
+    }
+
+    pub fn paths(&self) -> &[PathBuf] {
+        &self.paths
+    }
+
+    // Returns true if any of the files changed since the last call
+    pub fn poll(&mut self) -> bool {
+        let current = Self::snapshot(&self.paths);
+        if current == self.last_seen {
+            return false;
+        }
+
+        self.last_seen = current;
+        true
+    }
+
+    pub fn wait_for_change(&mut self) {
+        while !self.poll() {
+            std::thread::sleep(POLL_INTERVAL);
+        }
+    }
+}
+
+#[test]
+pub fn run_test_poll() -> std::io::Result<()> {
+    let path = std::env::temp_dir().join(format!("aoc_watch_test_{}.txt", std::process::id()));
+    std::fs::write(&path, "1")?;
+
+    let mut watcher = Watcher::new(vec![path.clone()]);
+    assert!(!watcher.poll());
+
+    std::fs::write(&path, "12")?;
+    assert!(watcher.poll());
+    assert!(!watcher.poll());
+
+    std::fs::remove_file(&path)?;
+    assert!(watcher.poll());
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use std::fmt::Write;
+use std::io::IsTerminal;
+
+use crate::utility::generic_error::GenericError;
+
+const RED: &str = "\x1b[1;31m";
+const BLUE: &str = "\x1b[1;34m";
+const BOLD: &str = "\x1b[1m";
+const RESET: &str = "\x1b[0m";
+
+struct Painter {
+    colour: bool,
+}
+
+impl Painter {
+    fn paint(&self, style: &str, text: &str) -> String {
+        if self.colour {
+            format!("{}{}{}", style, text, RESET)
+        } else {
+            text.to_string()
+        }
+    }
+}
+
+fn header(painter: &Painter, error: &GenericError) -> String {
+    format!("{}: {}\n", painter.paint(RED, "error"), painter.paint(BOLD, &error.to_string()))
+}
+
+fn unshared(mut error: &GenericError) -> &GenericError {
+    while let GenericError::SharedError(inner) = error {
+        error = inner;
+    }
+    error
+}
+
+// Renders an error like a compiler diagnostic, input errors get the offending line with the bad span underlined
+pub fn render(error: &GenericError, colour: bool) -> String {
+    let painter = Painter { colour };
+    let GenericError::Located { source, line, column, length, text, hint, error } = unshared(error) else {
+        return header(&painter, unshared(error));
+    };
+
+    let location = match source {
+        Some(source) => format!("{}:{}:{}", source, line, column),
+        None => format!("line {}, column {}", line, column),
+    };
+    let number = line.to_string();
+    let margin = " ".repeat(number.len());
+    // Tabs are kept so the underline lines up however wide the terminal draws them
+    let indent: String = text.chars().take(column - 1).map(|c| if c == '\t' { '\t' } else { ' ' }).collect();
+
+    let mut result = header(&painter, error);
+    let _ = writeln!(result, "{}{} {}", margin, painter.paint(BLUE, "-->"), location);
+    let _ = writeln!(result, "{} {}", margin, painter.paint(BLUE, "|"));
+    let _ = writeln!(result, "{} {}", painter.paint(BLUE, &format!("{} |", number)), text);
+    let _ = writeln!(result, "{} {} {}{}", margin, painter.paint(BLUE, "|"), indent, painter.paint(RED, &"^".repeat((*length).max(1))));
+    if let Some(hint) = hint {
+        let _ = writeln!(result, "{} {} {}", margin, painter.paint(BLUE, "="), painter.paint(BOLD, &format!("hint: {}", hint)));
+    }
+    result
+}
+
+// Coloured when stderr is a terminal
+pub fn report(error: &GenericError) {
+    eprint!("{}", render(error, std::io::stderr().is_terminal()));
+}
+
+#[test]
+pub fn run_test_render() {
+    use std::sync::Arc;
+
+    let located = GenericError::Located {
+        source: Some("input.txt".to_string()),
+        line: 12,
+        column: 4,
+        length: 2,
+        text: "75,4x,53".to_string(),
+        hint: Some("expected comma separated page numbers".to_string()),
+        error: Box::new(GenericError::BasicError("bad page".to_string())),
+    };
+    assert_eq!(render(&GenericError::SharedError(Arc::new(located)), false), "\
+error: basic error: bad page
+  --> input.txt:12:4
+   |
+12 | 75,4x,53
+   |    ^^
+   = hint: expected comma separated page numbers
+");
+
+    let located = GenericError::Located { source: None, line: 1, column: 5, length: 0, text: "190:".to_string(), hint: None, error: Box::new(GenericError::BasicError("empty".to_string())) };
+    assert_eq!(render(&located, false), "error: basic error: empty\n --> line 1, column 5\n  |\n1 | 190:\n  |     ^\n");
+
+    assert_eq!(render(&GenericError::Panic("oops".to_string()), false), "error: panicked: oops\n");
+    assert_eq!(render(&GenericError::Panic("oops".to_string()), true), "\x1b[1;31merror\x1b[0m: \x1b[1mpanicked: oops\x1b[0m\n");
+}
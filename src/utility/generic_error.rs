@@ -1,15 +1,31 @@
 use std::error::Error;
 use std::fmt::{Display, Formatter, Error as FmtError};
 use std::num::{ParseIntError, ParseFloatError};
+use std::sync::Arc;
+use std::time::Duration;
 
 #[derive(Debug)]
+#[allow(clippy::enum_variant_names)]
 pub enum GenericError {
     BasicError(String),
     ParseIntError(ParseIntError),
     ParseFloatError(ParseFloatError),
     IOError(std::io::Error),
-    SscanfError(sscanf::Error),
+    // sscanf::Error can hold a non-Send error, so only its message is kept
+    SscanfError(String),
     StrumParseError(strum::ParseError),
+    TomlParseError(toml::de::Error),
+    TomlWriteError(toml::ser::Error),
+    // Boxed as ureq::Error is large compared to the other variants
+    HttpError(Box<ureq::Error>),
+    // A solver panicked, holds the panic message
+    Panic(String),
+    Timeout(Duration),
+    // An error in the puzzle input, source is filled in by the runner as loaders only see the text.
+    // length is how many characters from column the error covers, hint says what was expected there
+    Located { source: Option<String>, line: usize, column: usize, length: usize, text: String, hint: Option<String>, error: Box<GenericError> },
+    // The same failure reported in more than one place, e.g. a parse error affecting both parts
+    SharedError(Arc<GenericError>),
 }
 
 impl From<ParseIntError> for GenericError {
@@ -32,7 +48,7 @@ impl From<std::io::Error> for GenericError {
 
 impl From<sscanf::Error> for GenericError {
     fn from(e: sscanf::Error) -> Self {
-        Self::SscanfError(e)
+        Self::SscanfError(e.to_string())
     }
 }
 
@@ -42,6 +58,41 @@ impl From<strum::ParseError> for GenericError {
     }
 }
 
+impl From<toml::de::Error> for GenericError {
+    fn from(e: toml::de::Error) -> Self {
+        Self::TomlParseError(e)
+    }
+}
+
+impl From<toml::ser::Error> for GenericError {
+    fn from(e: toml::ser::Error) -> Self {
+        Self::TomlWriteError(e)
+    }
+}
+
+impl From<ureq::Error> for GenericError {
+    fn from(e: ureq::Error) -> Self {
+        Self::HttpError(Box::new(e))
+    }
+}
+
+impl GenericError {
+    pub fn with_source(mut self, name: &str) -> Self {
+        if let Self::Located { source, .. } = &mut self {
+            source.get_or_insert_with(|| name.to_string());
+        }
+        self
+    }
+
+    // Only located errors have somewhere to show a hint, anything else is returned as is
+    pub fn with_hint(mut self, text: &str) -> Self {
+        if let Self::Located { hint, .. } = &mut self {
+            hint.get_or_insert_with(|| text.to_string());
+        }
+        self
+    }
+}
+
 impl Error for GenericError {}
 
 impl Display for GenericError {
@@ -53,6 +104,14 @@ impl Display for GenericError {
             Self::IOError(e) => write!(f, "io error: {}", e),
             Self::SscanfError(e) => write!(f, "sscanf error: {}", e),
             Self::StrumParseError(e) => write!(f, "strum parse error: {}", e),
+            Self::TomlParseError(e) => write!(f, "toml parse error: {}", e),
+            Self::TomlWriteError(e) => write!(f, "toml write error: {}", e),
+            Self::HttpError(e) => write!(f, "http error: {}", e),
+            Self::Panic(message) => write!(f, "panicked: {}", message),
+            Self::Timeout(limit) => write!(f, "timed out after {:?}", limit),
+            Self::Located { source: Some(source), line, column, error, .. } => write!(f, "{}:{}:{}: {}", source, line, column, error),
+            Self::Located { source: None, line, column, error, .. } => write!(f, "line {}, column {}: {}", line, column, error),
+            Self::SharedError(e) => write!(f, "{}", e),
         }
     }
 }
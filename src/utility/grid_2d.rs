@@ -66,7 +66,7 @@ impl<ElementType : std::clone::Clone> Grid<ElementType> {
     }
 
     fn convert_coordinates_to_index(&self, coordinates : Coordinates) -> i64 {
-        return coordinates.y * self.width as i64 + coordinates.x;
+        coordinates.y * self.width as i64 + coordinates.x
     }
 
     pub fn get_value(&self, coordinates : Coordinates) -> Option<&ElementType> {
@@ -95,7 +95,7 @@ impl<ElementType : std::clone::Clone> Grid<ElementType> {
     }
 
     pub fn num_rows(&self) -> usize {
-        self.buffer.len() / self.width as usize
+        self.buffer.len() / self.width
     }
 
     pub fn num_columns(&self) -> usize {
@@ -106,7 +106,7 @@ impl<ElementType : std::clone::Clone> Grid<ElementType> {
         self.buffer.len()
     }
 
-    pub fn iter(self: &Self) -> impl Iterator<Item=&ElementType>{
+    pub fn iter(&self) -> impl Iterator<Item=&ElementType>{
         self.buffer.iter()
     }
 }
\ No newline at end of file
@@ -0,0 +1,67 @@
+use std::str::FromStr;
+
+use crate::utility::generic_error::{GenericError, GenericResult};
+
+// A line of puzzle input that knows where it is, so errors can point back at it
+#[derive(Clone, Copy, Debug)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    // Characters before a token sliced out of this line, None if it came from somewhere else
+    fn offset_of(&self, token: &str) -> Option<usize> {
+        let offset = (token.as_ptr() as usize).wrapping_sub(self.text.as_ptr() as usize);
+        if offset > self.text.len() || token.len() > self.text.len() - offset {
+            return None;
+        }
+        Some(self.text[..offset].chars().count())
+    }
+
+    pub fn error(&self, error: impl Into<GenericError>) -> GenericError {
+        self.error_at(self.text, error)
+    }
+
+    pub fn error_at(&self, token: &str, error: impl Into<GenericError>) -> GenericError {
+        let (column, length) = match self.offset_of(token) {
+            Some(offset) => (offset + 1, token.chars().count()),
+            // A token from somewhere else can't be pointed at, so blame the whole line
+            None => (1, self.text.chars().count()),
+        };
+        match error.into() {
+            located @ GenericError::Located { .. } => located,
+            error => GenericError::Located { source: None, line: self.number, column, length, text: self.text.to_string(), hint: None, error: Box::new(error) },
+        }
+    }
+
+    pub fn parse<T: FromStr>(&self, token: &'a str) -> GenericResult<T> where T::Err: Into<GenericError> {
+        token.parse().map_err(|e: T::Err| self.error_at(token, e))
+    }
+}
+
+pub fn numbered_lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(index, text)| Line { number: index + 1, text })
+}
+
+// Errors parse_line doesn't locate itself are pointed at the start of their line
+pub fn parse_lines<T>(input: &str, mut parse_line: impl FnMut(&Line) -> GenericResult<T>) -> GenericResult<Vec<T>> {
+    numbered_lines(input).map(|line| parse_line(&line).map_err(|e| line.error(e))).collect()
+}
+
+#[test]
+pub fn run_test_parse_lines() {
+    let parse = |input| parse_lines(input, |line| line.text.split(' ').map(|token| line.parse::<u32>(token)).collect::<GenericResult<Vec<u32>>>());
+    assert_eq!(parse("1 2\n3 4\n").unwrap(), vec![vec![1, 2], vec![3, 4]]);
+
+    match parse("1 2\n3 x4\n") {
+        Err(GenericError::Located { source: None, line: 2, column: 3, length: 2, text, error, .. }) => {
+            assert_eq!(text, "3 x4");
+            assert!(matches!(*error, GenericError::ParseIntError(_)));
+        }
+        result => panic!("Expected a located error, got {:?}", result),
+    }
+
+    let error = parse_lines("ok\n\nok\n", |line| if line.text.is_empty() { Err(GenericError::BasicError("empty".to_string())) } else { Ok(()) }).unwrap_err();
+    assert_eq!(error.with_source("example.txt").to_string(), "example.txt:2:1: basic error: empty");
+}
@@ -0,0 +1,86 @@
+use std::fmt::Arguments;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    const ALL: [Level; 5] = [Level::Error, Level::Warn, Level::Info, Level::Debug, Level::Trace];
+
+    // Each -v shows one more level than the default of info, each -q one less
+    pub fn from_verbosity(verbose: u8, quiet: u8) -> Level {
+        let index = (Level::Info as i32 + verbose as i32 - quiet as i32).clamp(0, Level::Trace as i32);
+        Level::ALL[index as usize]
+    }
+}
+
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(Level::Info as u8);
+
+pub fn set_max_level(level: Level) {
+    MAX_LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn enabled(level: Level) -> bool {
+    level as u8 <= MAX_LEVEL.load(Ordering::Relaxed)
+}
+
+// Everything goes to stderr so stdout only ever holds answers
+pub fn write(level: Level, args: Arguments) {
+    match level {
+        Level::Error => eprintln!("error: {}", args),
+        Level::Warn => eprintln!("warning: {}", args),
+        Level::Info => eprintln!("{}", args),
+        Level::Debug => eprintln!("debug: {}", args),
+        Level::Trace => eprintln!("trace: {}", args),
+    }
+}
+
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::utility::log::enabled($level) {
+            $crate::utility::log::write($level, format_args!($($arg)+));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! log_error {
+    ($($arg:tt)+) => { $crate::log!($crate::utility::log::Level::Error, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! log_warn {
+    ($($arg:tt)+) => { $crate::log!($crate::utility::log::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! log_info {
+    ($($arg:tt)+) => { $crate::log!($crate::utility::log::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! log_debug {
+    ($($arg:tt)+) => { $crate::log!($crate::utility::log::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! log_trace {
+    ($($arg:tt)+) => { $crate::log!($crate::utility::log::Level::Trace, $($arg)+) };
+}
+
+#[test]
+pub fn run_test_from_verbosity() {
+    assert_eq!(Level::from_verbosity(0, 0), Level::Info);
+    assert_eq!(Level::from_verbosity(1, 0), Level::Debug);
+    assert_eq!(Level::from_verbosity(5, 0), Level::Trace);
+    assert_eq!(Level::from_verbosity(0, 1), Level::Warn);
+    assert_eq!(Level::from_verbosity(0, 9), Level::Error);
+    assert_eq!(Level::from_verbosity(1, 1), Level::Info);
+}
@@ -1,2 +1,5 @@
+pub mod diagnostic;
 pub mod generic_error;
-pub mod grid_2d;
\ No newline at end of file
+pub mod grid_2d;
+pub mod input_lines;
+pub mod log;
\ No newline at end of file